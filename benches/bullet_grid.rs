@@ -0,0 +1,66 @@
+//! Needs nightly: `cargo +nightly bench --features bench`
+#![feature(test)]
+
+extern crate test;
+
+use bevy::prelude::*;
+use test::Bencher;
+
+#[allow(dead_code)]
+#[path = "../src/bullet/grid.rs"]
+mod grid;
+use grid::{BulletGrid, GridBullet};
+
+const CELL_SIZE: f32 = 32.;
+const BULLET_RADIUS: f32 = 4.;
+const BULLET_SPACING: f32 = 8.;
+const BULLET_ROW: usize = 100;
+const HITBOX_HALF_SIZE: f32 = 2.;
+const GRAZE_RADIUS: f32 = 24.;
+
+// Spreads bullets on a grid around the player, so a few of them hit every frame
+fn bullet_positions(amount: usize) -> Vec<Vec2> {
+    let offset = Vec2::splat(BULLET_ROW as f32 * BULLET_SPACING / 2.);
+    (0..amount)
+        .map(|i| {
+            Vec2::new((i % BULLET_ROW) as f32, (i / BULLET_ROW) as f32) * BULLET_SPACING - offset
+        })
+        .collect()
+}
+
+// A frame of bullet collision: rebuilding the grid, then the hit and graze queries
+fn bullet_frame(bencher: &mut Bencher, amount: usize) {
+    let positions = bullet_positions(amount);
+    let mut grid = BulletGrid::new(CELL_SIZE);
+    let hitbox = Rect::from_center_half_size(Vec2::ZERO, Vec2::splat(HITBOX_HALF_SIZE));
+
+    bencher.iter(|| {
+        grid.clear();
+        for (i, position) in positions.iter().enumerate() {
+            grid.insert(GridBullet {
+                entity: Entity::from_raw(i as u32),
+                position: *position,
+                radius: BULLET_RADIUS,
+            });
+        }
+
+        let hits = grid.query_rect(hitbox).count();
+        let grazes = grid.query_circle(Vec2::ZERO, GRAZE_RADIUS).count();
+        test::black_box((hits, grazes))
+    });
+}
+
+#[bench]
+fn bullet_frame_1k(bencher: &mut Bencher) {
+    bullet_frame(bencher, 1_000);
+}
+
+#[bench]
+fn bullet_frame_5k(bencher: &mut Bencher) {
+    bullet_frame(bencher, 5_000);
+}
+
+#[bench]
+fn bullet_frame_10k(bencher: &mut Bencher) {
+    bullet_frame(bencher, 10_000);
+}
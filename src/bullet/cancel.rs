@@ -0,0 +1,176 @@
+use bevy::prelude::*;
+
+use super::config::cancel::*;
+use super::Bullet;
+use crate::bullet_pattern::Pattern;
+use crate::player::{stats::PlayerHit, Player};
+use crate::shared::move_toward_vec2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CancelArea {
+    // Everything visible by the camera, unfinished patterns included
+    Screen,
+    Radius { center: Vec2, radius: f32 },
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CancelBullets {
+    pub area: CancelArea,
+    // Turns cancelled bullets into point items
+    pub items: bool,
+}
+
+#[derive(Event, Debug, Default)]
+pub struct PointCollected;
+
+#[derive(Component)]
+pub struct CancelEffect(Timer);
+
+#[derive(Component)]
+pub struct PointItem(Timer);
+
+impl PointItem {
+    pub fn bundle(translation: Vec3) -> impl Bundle {
+        (
+            PointItem(Timer::from_seconds(POINT_ITEM_DELAY, TimerMode::Once)),
+            Sprite {
+                color: POINT_ITEM_COLOR,
+                custom_size: Some(Vec2::splat(POINT_ITEM_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(translation),
+        )
+    }
+}
+
+fn screen_rect(camera: &Camera, transform: &GlobalTransform) -> Option<Rect> {
+    let size = camera.logical_viewport_size()?;
+    let min = camera.viewport_to_world_2d(transform, Vec2::ZERO).ok()?;
+    let max = camera.viewport_to_world_2d(transform, size).ok()?;
+    Some(Rect::from_corners(min, max))
+}
+
+fn cancel_bullets(
+    mut cmd: Commands,
+    mut cancel_events: EventReader<CancelBullets>,
+    bullets: Query<(Entity, &GlobalTransform, &Sprite), With<Bullet>>,
+    patterns: Query<(Entity, &GlobalTransform), With<Pattern>>,
+    camera: Single<(&Camera, &GlobalTransform), With<Camera2d>>,
+) {
+    let (camera, camera_transform) = *camera;
+
+    for CancelBullets { area, items } in cancel_events.read() {
+        let screen = screen_rect(camera, camera_transform);
+
+        for (bullet, transform, sprite) in &bullets {
+            let position = transform.translation().truncate();
+            let inside = match area {
+                CancelArea::Screen => screen.is_none_or(|screen| screen.contains(position)),
+                CancelArea::Radius { center, radius } => position.distance(*center) <= *radius,
+            };
+            if !inside {
+                continue;
+            }
+
+            cmd.entity(bullet).try_despawn();
+            cmd.spawn((
+                CancelEffect(Timer::from_seconds(BULLET_CANCEL_TIME, TimerMode::Once)),
+                sprite.clone(),
+                Transform::from_translation(transform.translation()),
+            ));
+
+            if *items {
+                cmd.spawn(PointItem::bundle(transform.translation()));
+            }
+        }
+
+        // Unfinished patterns on screen would keep spawning bullets
+        if *area == CancelArea::Screen {
+            for (pattern, transform) in &patterns {
+                let position = transform.translation().truncate();
+                if screen.is_none_or(|screen| screen.contains(position)) {
+                    cmd.entity(pattern).try_despawn();
+                }
+            }
+        }
+    }
+}
+
+fn cancel_on_hit(
+    mut hit_events: EventReader<PlayerHit>,
+    player: Single<&GlobalTransform, With<Player>>,
+    mut cancel_event: EventWriter<CancelBullets>,
+) {
+    if hit_events.read().count() > 0 {
+        cancel_event.write(CancelBullets {
+            area: CancelArea::Radius {
+                center: player.translation().truncate(),
+                radius: BULLET_CANCEL_HIT_RADIUS,
+            },
+            items: false,
+        });
+    }
+}
+
+// Shrinks and fades out cancelled bullets
+fn cancel_effect(
+    mut cmd: Commands,
+    mut effects: Query<(Entity, &mut CancelEffect, &mut Sprite, &mut Transform)>,
+    time: Res<Time>,
+) {
+    for (effect, mut timer, mut sprite, mut transform) in &mut effects {
+        if timer.0.tick(time.delta()).finished() {
+            cmd.entity(effect).despawn();
+            continue;
+        }
+
+        let left = timer.0.fraction_remaining();
+        sprite.color = sprite.color.with_alpha(left);
+        transform.scale = Vec3::splat(left);
+    }
+}
+
+fn point_item_movement(
+    mut cmd: Commands,
+    mut items: Query<(Entity, &mut PointItem, &mut Transform)>,
+    player: Single<&GlobalTransform, With<Player>>,
+    mut collected_event: EventWriter<PointCollected>,
+    time: Res<Time>,
+) {
+    let target = player.translation().truncate();
+    let delta = time.delta_secs_f64();
+
+    for (item, mut timer, mut transform) in &mut items {
+        if !timer.0.tick(time.delta()).finished() {
+            transform.translation.y += POINT_ITEM_RISE_SPEED * delta as f32;
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+        if position.distance(target) <= POINT_ITEM_COLLECT_RADIUS {
+            cmd.entity(item).despawn();
+            collected_event.write(PointCollected);
+            continue;
+        }
+
+        let position = move_toward_vec2(position, target, POINT_ITEM_SPEED * delta);
+        transform.translation = position.extend(transform.translation.z);
+    }
+}
+
+pub struct BulletCancelPlugin;
+
+impl Plugin for BulletCancelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CancelBullets>()
+            .add_event::<PointCollected>()
+            .add_systems(
+                Update,
+                (
+                    (cancel_on_hit, cancel_bullets).chain(),
+                    cancel_effect,
+                    point_item_movement,
+                ),
+            );
+    }
+}
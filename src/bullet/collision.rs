@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::RigidBodyDisabled;
+
+use super::config::collision::{BULLET_GRID_CELL_SIZE, BULLET_HITBOX_RADIUS};
+use super::grid::{BulletGrid, GridBullet};
+use super::Bullet;
+use crate::player::{hitbox::PlayerHitbox, stats::ChangeHealth, Player};
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BulletHitbox {
+    pub radius: f32,
+}
+
+impl Default for BulletHitbox {
+    fn default() -> Self {
+        BulletHitbox {
+            radius: BULLET_HITBOX_RADIUS,
+        }
+    }
+}
+
+pub fn update_bullet_grid(
+    mut grid: ResMut<BulletGrid>,
+    bullets: Query<
+        (Entity, &GlobalTransform, &BulletHitbox),
+        (With<Bullet>, Without<RigidBodyDisabled>),
+    >,
+) {
+    grid.clear();
+    for (entity, transform, hitbox) in &bullets {
+        grid.insert(GridBullet {
+            entity,
+            position: transform.translation().truncate(),
+            radius: hitbox.radius,
+        });
+    }
+}
+
+// Bullets are used up by a hit, so the player isn't hit again by the same one
+pub fn bullet_player_collision(
+    mut cmd: Commands,
+    grid: Res<BulletGrid>,
+    player: Single<(&GlobalTransform, &PlayerHitbox), With<Player>>,
+    mut health_event: EventWriter<ChangeHealth>,
+) {
+    let (transform, hitbox) = *player;
+    let rect = hitbox.rect(transform.translation().truncate());
+
+    let mut hit = false;
+    for bullet in grid.query_rect(rect) {
+        cmd.entity(bullet.entity).try_despawn();
+        hit = true;
+    }
+
+    if hit {
+        health_event.write(ChangeHealth(-1));
+    }
+}
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BulletGridUpdate;
+
+pub struct BulletCollisionPlugin;
+
+impl Plugin for BulletCollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(BulletGrid::new(BULLET_GRID_CELL_SIZE))
+            .add_systems(Update, update_bullet_grid.in_set(BulletGridUpdate))
+            .add_systems(Update, bullet_player_collision.after(BulletGridUpdate));
+    }
+}
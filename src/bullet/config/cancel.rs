@@ -0,0 +1,12 @@
+use bevy::color::Color;
+
+pub const BULLET_CANCEL_TIME: f32 = 0.25;
+pub const BULLET_CANCEL_HIT_RADIUS: f32 = 64.;
+
+pub const POINT_ITEM_SIZE: f32 = 4.;
+pub const POINT_ITEM_COLOR: Color = Color::srgb(0.45, 0.65, 1.);
+// Time spent floating up before flying to the player
+pub const POINT_ITEM_DELAY: f32 = 0.4;
+pub const POINT_ITEM_RISE_SPEED: f32 = 40.;
+pub const POINT_ITEM_SPEED: f64 = 320.;
+pub const POINT_ITEM_COLLECT_RADIUS: f32 = 8.;
@@ -0,0 +1,2 @@
+pub const BULLET_HITBOX_RADIUS: f32 = 4.;
+pub const BULLET_GRID_CELL_SIZE: f32 = 32.;
@@ -1,2 +1,4 @@
 pub mod animation;
+pub mod cancel;
+pub mod collision;
 // pub mod physics;
@@ -0,0 +1,137 @@
+// Kept free of other game modules, so benches/bullet_grid.rs can include it on its own
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy)]
+pub struct GridBullet {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub radius: f32,
+}
+
+impl GridBullet {
+    pub fn overlaps_rect(&self, rect: Rect) -> bool {
+        let closest = self.position.clamp(rect.min, rect.max);
+        self.position.distance_squared(closest) <= self.radius * self.radius
+    }
+
+    pub fn overlaps_circle(&self, center: Vec2, radius: f32) -> bool {
+        let reach = self.radius + radius;
+        self.position.distance_squared(center) <= reach * reach
+    }
+}
+
+// Spatial hash of every active bullet, rebuilt each frame
+#[derive(Resource)]
+pub struct BulletGrid {
+    cells: HashMap<IVec2, Vec<GridBullet>>,
+    cell_size: f32,
+    max_radius: f32,
+}
+
+impl BulletGrid {
+    pub fn new(cell_size: f32) -> Self {
+        BulletGrid {
+            cells: HashMap::new(),
+            cell_size,
+            max_radius: 0.,
+        }
+    }
+
+    fn cell(&self, position: Vec2) -> IVec2 {
+        (position / self.cell_size).floor().as_ivec2()
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.values_mut().for_each(Vec::clear);
+        self.max_radius = 0.;
+    }
+
+    pub fn insert(&mut self, bullet: GridBullet) {
+        self.max_radius = self.max_radius.max(bullet.radius);
+        self.cells
+            .entry(self.cell(bullet.position))
+            .or_default()
+            .push(bullet);
+    }
+
+    // Bullets in cells that could overlap the rect, needs a narrow phase check
+    pub fn candidates(&self, rect: Rect) -> impl Iterator<Item = &GridBullet> {
+        let min = self.cell(rect.min - self.max_radius);
+        let max = self.cell(rect.max + self.max_radius);
+
+        (min.x..=max.x)
+            .flat_map(move |x| (min.y..=max.y).map(move |y| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+    }
+
+    pub fn query_rect(&self, rect: Rect) -> impl Iterator<Item = &GridBullet> {
+        self.candidates(rect)
+            .filter(move |bullet| bullet.overlaps_rect(rect))
+    }
+
+    pub fn query_circle(&self, center: Vec2, radius: f32) -> impl Iterator<Item = &GridBullet> {
+        self.candidates(Rect::from_center_half_size(center, Vec2::splat(radius)))
+            .filter(move |bullet| bullet.overlaps_circle(center, radius))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CELL_SIZE: f32 = 32.;
+
+    fn grid(bullets: &[(Vec2, f32)]) -> BulletGrid {
+        let mut grid = BulletGrid::new(CELL_SIZE);
+        for (i, (position, radius)) in bullets.iter().enumerate() {
+            grid.insert(GridBullet {
+                entity: Entity::from_raw(i as u32),
+                position: *position,
+                radius: *radius,
+            });
+        }
+        grid
+    }
+
+    fn indices<'a>(bullets: impl Iterator<Item = &'a GridBullet>) -> Vec<u32> {
+        let mut indices = bullets
+            .map(|bullet| bullet.entity.index())
+            .collect::<Vec<_>>();
+        indices.sort();
+        indices
+    }
+
+    #[test]
+    fn grid_cell_boundaries() {
+        let grid = grid(&[
+            (Vec2::new(31.5, 0.), 1.),
+            (Vec2::new(32.5, 0.), 1.),
+            (Vec2::new(64.5, 0.), 1.),
+            (Vec2::new(-0.5, -0.5), 1.),
+        ]);
+        let rect = Rect::new(0., 0., 31., 1.);
+
+        // Only neighbouring cells within the largest radius are looked at
+        assert_eq!(indices(grid.candidates(rect)), vec![0, 1, 3]);
+        assert_eq!(indices(grid.query_rect(rect)), vec![0, 3]);
+    }
+
+    #[test]
+    fn grid_mixed_radii() {
+        let grid = grid(&[
+            (Vec2::new(90., 0.), 40.),
+            (Vec2::new(55., 0.), 2.),
+            (Vec2::new(200., 0.), 2.),
+        ]);
+        let rect = Rect::from_center_half_size(Vec2::new(50., 0.), Vec2::splat(2.));
+
+        assert_eq!(indices(grid.candidates(rect)), vec![0, 1]);
+        assert_eq!(indices(grid.query_rect(rect)), vec![0]);
+        assert_eq!(
+            indices(grid.query_circle(Vec2::new(50., 0.), 4.)),
+            vec![0, 1]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::RigidBodyDisabled;
+use serde::Deserialize;
+
+use super::Bullet;
+use crate::{
+    bullet_pattern::{construction::ConstructionType, PatternBundle, PatternParams},
+    physics::movement::{MovementTime, MovementType},
+};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum PayloadTrigger {
+    // Seconds since the bullet was released
+    Delay(f32),
+    // Distance from the point where the bullet was released
+    Distance(f32),
+}
+
+impl PayloadTrigger {
+    fn triggered(&self, elapsed: f32, travelled: f32) -> bool {
+        match *self {
+            PayloadTrigger::Delay(delay) => elapsed >= delay,
+            PayloadTrigger::Distance(distance) => travelled >= distance,
+        }
+    }
+}
+
+/// Pattern spawned in place of the bullet once the trigger goes off
+#[derive(Component, Debug, Clone, PartialEq, Deserialize)]
+pub struct BulletPayload {
+    pub trigger: PayloadTrigger,
+    pub params: PatternParams,
+    pub construction: ConstructionType,
+    #[serde(default)]
+    pub movement: MovementType,
+}
+
+#[derive(Component, Default)]
+pub struct Travelled {
+    origin: Option<Vec2>,
+    distance: f32,
+}
+
+impl Travelled {
+    // The first position seen is where the bullet was released
+    fn update(&mut self, position: Vec2) {
+        let origin = *self.origin.get_or_insert(position);
+        self.distance = position.distance(origin);
+    }
+}
+
+fn track_travelled(
+    mut bullets: Query<(&GlobalTransform, &mut Travelled), Without<RigidBodyDisabled>>,
+) {
+    for (transform, mut travelled) in &mut bullets {
+        travelled.update(transform.translation().truncate());
+    }
+}
+
+fn trigger_payload(
+    bullets: Query<
+        (
+            Entity,
+            &BulletPayload,
+            &MovementTime,
+            &Travelled,
+            &GlobalTransform,
+        ),
+        (With<Bullet>, Without<RigidBodyDisabled>),
+    >,
+    mut cmd: Commands,
+) {
+    for (bullet, payload, movement_time, travelled, transform) in &bullets {
+        let elapsed = movement_time.0.elapsed_secs();
+        if !payload.trigger.triggered(elapsed, travelled.distance) {
+            continue;
+        }
+
+        cmd.entity(bullet).despawn();
+        cmd.spawn(PatternBundle {
+            transform: Transform::from_translation(transform.translation()),
+            ..PatternBundle::new(
+                payload.params.clone(),
+                payload.construction.clone(),
+                payload.movement.clone(),
+            )
+        });
+    }
+}
+
+pub struct BulletPayloadPlugin;
+
+impl Plugin for BulletPayloadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (track_travelled, trigger_payload).chain());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_delay() {
+        let trigger = PayloadTrigger::Delay(0.5);
+        assert!(!trigger.triggered(0.4, 100.));
+        assert!(trigger.triggered(0.5, 0.));
+    }
+
+    #[test]
+    fn payload_distance() {
+        let trigger = PayloadTrigger::Distance(10.);
+        let mut travelled = Travelled::default();
+
+        travelled.update(Vec2::new(5., 5.));
+        travelled.update(Vec2::new(11., 5.));
+        assert!(!trigger.triggered(10., travelled.distance));
+
+        // Measured from the release point, not summed up along the way
+        travelled.update(Vec2::new(5., 15.));
+        assert!(trigger.triggered(0., travelled.distance));
+    }
+}
@@ -2,7 +2,6 @@ use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
 use super::Bullet;
-use crate::physics::Acceleration;
 
 // fn bullet_physics_enabled(
 //     mut removals: RemovedComponents<RigidBodyDisabled>,
@@ -17,10 +16,21 @@ use crate::physics::Acceleration;
 //     false
 // }
 
+// Bullets don't have rapier bodies, so they're moved relative to their pattern here
+pub fn bullet_velocity(
+    mut bullets: Query<(&Velocity, &mut Transform), (With<Bullet>, Without<RigidBodyDisabled>)>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_secs();
+    for (velocity, mut transform) in &mut bullets {
+        transform.translation += (velocity.linvel * delta).extend(0.);
+    }
+}
+
 pub struct BulletPhysicsPlugin;
 
 impl Plugin for BulletPhysicsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, bullet_acceleration);
+        app.add_systems(Update, bullet_velocity);
     }
 }
@@ -0,0 +1,121 @@
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::{construction::ConstructionType, Pattern, PatternConstruction, PatternParams};
+use crate::enemy::spawner::turret::TurretAmmo;
+
+#[derive(Asset, TypePath, Debug, Deserialize)]
+pub struct PatternAsset {
+    pub ammo: Vec<TurretAmmo>,
+}
+
+/// Remembers which entry of which pattern file the pattern was built from
+#[derive(Component, Clone)]
+pub struct PatternSource {
+    pub handle: Handle<PatternAsset>,
+    pub index: usize,
+}
+
+#[derive(Debug, Error)]
+pub enum PatternAssetError {
+    #[error("couldn't read pattern file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("couldn't parse pattern file: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+#[derive(Default)]
+pub struct PatternAssetLoader;
+
+impl AssetLoader for PatternAssetLoader {
+    type Asset = PatternAsset;
+    type Settings = ();
+    type Error = PatternAssetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes::<PatternAsset>(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["pattern.ron"]
+    }
+}
+
+fn log_pattern_reload(
+    mut events: EventReader<AssetEvent<PatternAsset>>,
+    mut failed_events: EventReader<AssetLoadFailedEvent<PatternAsset>>,
+    server: Res<AssetServer>,
+) {
+    for event in events.read() {
+        if let AssetEvent::Modified { id } = event {
+            match server.get_path(*id) {
+                Some(path) => info!("Reloaded pattern: {path}"),
+                None => info!("Reloaded pattern: {id}"),
+            }
+        }
+    }
+
+    for AssetLoadFailedEvent { path, error, .. } in failed_events.read() {
+        error!("Couldn't load pattern {path}: {error}");
+    }
+}
+
+/// Applies edited pattern files to the patterns that are still being constructed
+fn reload_patterns(
+    mut events: EventReader<AssetEvent<PatternAsset>>,
+    mut patterns: Query<
+        (
+            &PatternSource,
+            &PatternConstruction,
+            &mut PatternParams,
+            &mut ConstructionType,
+        ),
+        With<Pattern>,
+    >,
+    assets: Res<Assets<PatternAsset>>,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        let Some(asset) = assets.get(*id) else {
+            continue;
+        };
+
+        for (source, construction, mut params, mut construction_type) in &mut patterns {
+            if source.handle.id() != *id || construction.finished {
+                continue;
+            }
+            let Some(TurretAmmo::Pattern {
+                params: new_params,
+                construction: new_construction,
+                ..
+            }) = asset.ammo.get(source.index)
+            else {
+                continue;
+            };
+
+            *params = new_params.clone();
+            *construction_type = new_construction.clone();
+        }
+    }
+}
+
+pub struct PatternAssetPlugin;
+
+impl Plugin for PatternAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<PatternAsset>()
+            .init_asset_loader::<PatternAssetLoader>()
+            .add_systems(Update, (log_pattern_reload, reload_patterns));
+    }
+}
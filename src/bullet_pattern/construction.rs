@@ -1,13 +1,17 @@
 use bevy::prelude::*;
 use bevy_inspector_egui::egui::emath::Numeric;
+use serde::Deserialize;
 use std::f32::consts::PI;
 
-#[derive(Component, Default, Debug, Clone)]
+use crate::physics::movement::MovementType;
+
+#[derive(Component, Default, Debug, Clone, PartialEq, Deserialize)]
 pub enum ConstructionType {
     #[default]
     Circle,
     RegularPolygon(u64),
     Shuriken(u64),
+    Grid(GridLayout),
 }
 
 impl ConstructionType {
@@ -41,41 +45,296 @@ impl ConstructionType {
                 Vec2::from_angle((progress as f32 * 2. * PI) / bullet_amount as f32 + phi)
                     * (1. / phi.cos())
             }
+
+            Grid(layout) => layout.translation(progress, bullet_amount),
+        }
+    }
+}
+
+/// Movement of the `progress`-th bullet of a pattern, placed at `offset` from its center
+pub fn bullet_movement(movement: &MovementType, progress: u64, offset: Vec2) -> MovementType {
+    match movement.clone() {
+        MovementType::Wave {
+            speed,
+            direction,
+            amplitude,
+            frequency,
+            phase,
+            phase_step,
+        } => MovementType::Wave {
+            speed,
+            direction: match direction == Vec2::ZERO {
+                true => offset.normalize_or_zero(),
+                false => direction,
+            },
+            amplitude,
+            frequency,
+            phase: phase + phase_step * progress as f32,
+            phase_step,
+        },
+        movement => movement,
+    }
+}
+
+/// Formation drawn as ASCII art, every non-whitespace character is a bullet.
+///
+/// Points are ordered along the outline of the drawing and normalized
+/// so that the furthest one lies on the unit circle, same as `Circle`.
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "Vec<String>")]
+pub struct GridLayout {
+    points: Vec<Vec2>,
+    // Length of the segment from each point to the next one, zero where it isn't drawn
+    lengths: Vec<f32>,
+}
+
+impl GridLayout {
+    pub fn from_rows<S: AsRef<str>>(rows: &[S]) -> Self {
+        let cells = get_pattern_points(rows);
+        let lengths = segment_lengths(&cells);
+
+        let (min, max) = cells
+            .iter()
+            .fold((IVec2::MAX, IVec2::MIN), |(min, max), cell| {
+                (min.min(*cell), max.max(*cell))
+            });
+        let center = (min + max).as_vec2() / 2.;
+
+        // Rows go downwards, bevy's y axis goes upwards
+        let points = cells
+            .iter()
+            .map(|cell| (cell.as_vec2() - center) * Vec2::new(1., -1.))
+            .collect::<Vec<_>>();
+
+        let radius = points.iter().map(|point| point.length()).fold(0., f32::max);
+        let points = match radius > 0. {
+            true => points.iter().map(|point| *point / radius).collect(),
+            false => points,
+        };
+
+        GridLayout { points, lengths }
+    }
+
+    pub fn translation(&self, progress: u64, bullet_amount: u64) -> Vec2 {
+        outline_point(
+            &self.points,
+            &self.lengths,
+            progress as usize,
+            bullet_amount as usize,
+        )
+    }
+}
+
+impl From<Vec<String>> for GridLayout {
+    fn from(rows: Vec<String>) -> Self {
+        GridLayout::from_rows(&rows)
+    }
+}
+
+fn neighbours(a: IVec2, b: IVec2) -> bool {
+    (a - b).abs().max_element() <= 1
+}
+
+/// Grid coordinates of the drawn points in the order they're traced.
+///
+/// Each group of touching cells is walked from neighbour to neighbour before
+/// jumping to the closest cell of the next group, ties go in reading order.
+fn get_pattern_points<S: AsRef<str>>(rows: &[S]) -> Vec<IVec2> {
+    let cells = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.as_ref()
+                .chars()
+                .enumerate()
+                .filter(|(_, char)| !char.is_whitespace())
+                .map(move |(x, _)| IVec2::new(x as i32, y as i32))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Flood fill the groups of touching cells
+    let mut groups = vec![usize::MAX; cells.len()];
+    for start in 0..cells.len() {
+        if groups[start] != usize::MAX {
+            continue;
+        }
+        groups[start] = start;
+        let mut stack = vec![start];
+        while let Some(cell) = stack.pop() {
+            for other in 0..cells.len() {
+                if groups[other] == usize::MAX && neighbours(cells[cell], cells[other]) {
+                    groups[other] = start;
+                    stack.push(other);
+                }
+            }
         }
     }
+
+    let mut visited = vec![false; cells.len()];
+    let mut order = Vec::with_capacity(cells.len());
+    let mut current = 0;
+    while order.len() < cells.len() {
+        visited[current] = true;
+        order.push(cells[current]);
+
+        let closest = |same_group: bool| {
+            (0..cells.len())
+                .filter(|i| !visited[*i] && (!same_group || groups[*i] == groups[current]))
+                .min_by_key(|i| (cells[*i].distance_squared(cells[current]), *i))
+        };
+
+        match closest(true).or_else(|| closest(false)) {
+            Some(next) => current = next,
+            None => break,
+        }
+    }
+
+    order
 }
 
-/*
-fn get_pattern_points(pattern: Vec<Vec<&str>>) -> Vec<IVec2> {
-    todo!()
+/// Length of every segment of the closed outline that extra bullets may be put on.
+///
+/// These are the segments between touching cells, or between lone cells that
+/// outline a shape on their own, never the jumps between separate strokes.
+fn segment_lengths(cells: &[IVec2]) -> Vec<f32> {
+    let lone = |cell: IVec2| {
+        !cells
+            .iter()
+            .any(|other| *other != cell && neighbours(cell, *other))
+    };
+
+    (0..cells.len())
+        .map(|i| {
+            let (a, b) = (cells[i], cells[(i + 1) % cells.len()]);
+            match neighbours(a, b) || (lone(a) && lone(b)) {
+                true => a.as_vec2().distance(b.as_vec2()),
+                false => 0.,
+            }
+        })
+        .collect()
 }
 
-fn extrapolate(points: Vec<IVec2>) -> Vec<IVec2> {
-    todo!()
+/// Picks the `progress`-th of `amount` points spread along the closed outline.
+///
+/// All drawn points are kept when `amount` exceeds their count,
+/// the extra ones are distributed between them proportionally to segment length.
+fn outline_point(points: &[Vec2], lengths: &[f32], progress: usize, amount: usize) -> Vec2 {
+    let n = points.len();
+    if n == 0 || amount == 0 {
+        return Vec2::ZERO;
+    }
+
+    if amount <= n {
+        return points[progress * n / amount % n];
+    }
+
+    let perimeter: f32 = lengths.iter().sum();
+    let extra = amount - n;
+
+    if perimeter <= 0. {
+        return points[progress % n];
+    }
+    // Largest remainder method, so the quotas always add up to `extra`
+    let shares = lengths
+        .iter()
+        .map(|length| extra as f32 * length / perimeter)
+        .collect::<Vec<_>>();
+    let mut quotas = shares
+        .iter()
+        .map(|share| share.floor() as usize)
+        .collect::<Vec<_>>();
+
+    let mut by_remainder = (0..n).collect::<Vec<_>>();
+    by_remainder.sort_by(|a, b| {
+        (shares[*b] - shares[*b].floor()).total_cmp(&(shares[*a] - shares[*a].floor()))
+    });
+
+    let missing = extra - quotas.iter().sum::<usize>();
+    for i in by_remainder.into_iter().take(missing) {
+        quotas[i] += 1;
+    }
+
+    let mut progress = progress % amount;
+    for (i, quota) in quotas.into_iter().enumerate() {
+        if progress <= quota {
+            let t = progress as f32 / (quota + 1) as f32;
+            return points[i].lerp(points[(i + 1) % n], t);
+        }
+        progress -= quota + 1;
+    }
+
+    unreachable!("progress is always less than the sum of segments")
 }
 
-#[test]
-fn construction_extrapolation() {
-    let pattern = get_pattern_points(vec![
-        vec!["*", " ", " ", " ", " ", " ", "*"],
-        vec![" ", " ", " ", " ", " ", " ", " "],
-        vec![" ", " ", " ", " ", " ", " ", " "],
-        vec![" ", " ", " ", " ", " ", " ", " "],
-        vec![" ", " ", " ", " ", " ", " ", " "],
-        vec![" ", " ", " ", " ", " ", " ", " "],
-        vec!["*", " ", " ", " ", " ", " ", "*"],
-    ]);
-
-    let res_pattern = get_pattern_points(vec![
-        vec!["*", " ", " ", "*", " ", " ", "*"],
-        vec![" ", " ", " ", " ", " ", " ", " "],
-        vec![" ", " ", " ", " ", " ", " ", " "],
-        vec!["*", " ", " ", " ", " ", " ", "*"],
-        vec![" ", " ", " ", " ", " ", " ", " "],
-        vec![" ", " ", " ", " ", " ", " ", " "],
-        vec!["*", " ", " ", "*", " ", " ", "*"],
-    ]);
-
-    assert_eq!(extrapolate(pattern), res_pattern);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extrapolate(cells: &[IVec2], amount: usize) -> Vec<Vec2> {
+        let points = cells.iter().map(|cell| cell.as_vec2()).collect::<Vec<_>>();
+        (0..amount)
+            .map(|progress| outline_point(&points, &segment_lengths(cells), progress, amount))
+            .collect()
+    }
+
+    fn as_points(cells: &[IVec2]) -> Vec<Vec2> {
+        cells.iter().map(|cell| cell.as_vec2()).collect()
+    }
+
+    #[test]
+    fn construction_extrapolation() {
+        let pattern = get_pattern_points(&[
+            "*     *", "       ", "       ", "       ", "       ", "       ", "*     *",
+        ]);
+
+        let res_pattern = get_pattern_points(&[
+            "*  *  *", "       ", "       ", "*     *", "       ", "       ", "*  *  *",
+        ]);
+
+        assert_eq!(
+            extrapolate(&pattern, res_pattern.len()),
+            as_points(&res_pattern)
+        );
+    }
+
+    #[test]
+    fn construction_fewer_bullets_than_points() {
+        let pattern = get_pattern_points(&[
+            "*  *  *", "       ", "       ", "*     *", "       ", "       ", "*  *  *",
+        ]);
+
+        let res_pattern = get_pattern_points(&[
+            "*     *", "       ", "       ", "       ", "       ", "       ", "*     *",
+        ]);
+
+        assert_eq!(extrapolate(&pattern, 4), as_points(&res_pattern));
+    }
+
+    #[test]
+    fn construction_interior_cells() {
+        let pattern = get_pattern_points(&["***", "***", "***"]);
+
+        assert_eq!(pattern.len(), 9);
+        for i in 0..pattern.len() {
+            assert!(!pattern[i + 1..].contains(&pattern[i]));
+        }
+        // Filled shapes are still traced without leaving the drawing
+        for segment in pattern.windows(2) {
+            assert!(neighbours(segment[0], segment[1]));
+        }
+    }
+
+    #[test]
+    fn construction_non_convex() {
+        let pattern = get_pattern_points(&["*   *", "*   *", "*****"]);
+
+        for segment in pattern.windows(2) {
+            assert!(neighbours(segment[0], segment[1]));
+        }
+        // Nothing is put across the opening at the top
+        for point in extrapolate(&pattern, 30) {
+            assert!(point.x == 0. || point.x == 4. || point.y == 2.);
+        }
+    }
 }
-*/
@@ -1,15 +1,22 @@
-use crate::bullet::BulletBundle;
+use crate::bullet::{
+    payload::{BulletPayload, Travelled},
+    BulletBundle,
+};
 use crate::colliders::SensorBundle;
 use crate::physics::movement::MovementType;
 use crate::physics::{despawn_no_children, Acceleration, DespawnIfNoChildren};
+use crate::shared::deserialize_secs;
 use bevy::prelude::*;
 use bevy_aseprite_ultra::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
 use bevy_rapier2d::prelude::*;
+use serde::Deserialize;
 use std::time::Duration;
 
+pub mod asset;
+use asset::PatternAssetPlugin;
 pub mod construction;
-use construction::ConstructionType;
+use construction::{bullet_movement, ConstructionType};
 
 #[derive(PartialEq, Debug, Default, Component)]
 pub struct Pattern;
@@ -21,12 +28,15 @@ pub struct PatternConstruction {
     pub finished: bool,
 }
 
-#[derive(PartialEq, Debug, Default, Component, Clone)]
+#[derive(PartialEq, Debug, Default, Component, Clone, Deserialize)]
 pub struct PatternParams {
     pub scale: f64,
     pub bullet_amount: u64,
     pub bullet_movement: MovementType,
+    #[serde(deserialize_with = "deserialize_secs")]
     pub construction_frequency: Duration,
+    #[serde(default)]
+    pub bullet_payload: Option<Box<BulletPayload>>,
 }
 
 #[derive(Bundle, LdtkEntity, Default)]
@@ -77,6 +87,7 @@ impl PatternParams {
                     .expect("CirclePattern should have construction_speed defined")
                     as f64,
             ),
+            bullet_payload: None,
         }
     }
 }
@@ -144,7 +155,11 @@ fn construction(
             .spawn(BulletBundle {
                 name: Name::from("Bullet"),
                 transform: Transform::from_translation(translation),
-                movement: params.bullet_movement.clone(),
+                movement: bullet_movement(
+                    &params.bullet_movement,
+                    construction.progress,
+                    translation.truncate(),
+                ),
                 ..default()
             })
             .id();
@@ -152,6 +167,11 @@ fn construction(
 
         cmd.entity(bullet).insert(RigidBodyDisabled);
         cmd.entity(bullet).insert(ChildOf(circle));
+
+        if let Some(payload) = &params.bullet_payload {
+            cmd.entity(bullet)
+                .insert((payload.as_ref().clone(), Travelled::default()));
+        }
     }
 }
 
@@ -218,6 +238,7 @@ pub struct PatternPlugin;
 impl Plugin for PatternPlugin {
     fn build(&self, app: &mut App) {
         app.register_ldtk_entity::<PatternBundle>("Pattern")
+            .add_plugins(PatternAssetPlugin)
             .add_systems(Update, construction_timer)
             .add_systems(Update, (construction, finish_construction).chain().after(despawn_no_children))
         // .add_systems(Update, bullet_acceleration)
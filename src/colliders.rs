@@ -99,6 +99,30 @@ impl From<&EntityInstance> for ColliderBundle {
                 },
                 ..Default::default()
             },
+            "Door" => ColliderBundle {
+                collider: Collider::cuboid(
+                    entity_instance.width as f32 / 2.,
+                    entity_instance.height as f32 / 2.,
+                ),
+                rigid_body: RigidBody::Fixed,
+                rotation_constraints,
+                ..Default::default()
+            },
+            "Mob" => ColliderBundle {
+                collider: Collider::cuboid(
+                    entity_instance.width as f32 / 2.,
+                    entity_instance.height as f32 / 2.,
+                ),
+                rigid_body: RigidBody::Dynamic,
+                friction: Friction {
+                    coefficient: 0.,
+                    combine_rule: CoefficientCombineRule::Min,
+                },
+                // Like the player, mobs fall through their own gravity in `patrol` only
+                gravity_scale: GravityScale(0.),
+                rotation_constraints,
+                ..Default::default()
+            },
             _ => ColliderBundle::default(),
         }
     }
@@ -1,2 +1,4 @@
 pub mod ldtk;
+pub mod save;
+pub mod score;
 pub mod world;
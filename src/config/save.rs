@@ -0,0 +1,6 @@
+use bevy::color::Color;
+
+pub const SAVE_FILE: &str = "save.ron";
+
+pub const CHECKPOINT_COLOR: Color = Color::srgb(1., 0.9, 0.38);
+pub const CHECKPOINT_REACHED_COLOR: Color = Color::srgb(0.4, 0.85, 0.5);
@@ -0,0 +1,7 @@
+pub const SCORE_GRAZE: u64 = 50;
+pub const SCORE_POINT_ITEM: u64 = 100;
+pub const SCORE_CARD_CAPTURE: u64 = 10_000;
+pub const SCORE_ENEMY_KILLED: u64 = 1_000;
+pub const SCORE_BOSS_DEFEATED: u64 = 50_000;
+
+pub const HI_SCORE_FILE: &str = "hiscore";
@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+use bevy_rapier2d::prelude::ColliderDisabled;
+
+use crate::colliders::ColliderBundle;
+use crate::item::{Inventory, Item};
+use crate::player::interact::{Interact, Interactable};
+use crate::player::Player;
+
+const DOOR_COLOR: Color = Color::srgb(0.75, 0.8, 0.86);
+const DOOR_LOCKED_COLOR: Color = Color::srgb(0.55, 0.45, 0.3);
+const DOOR_OPENED_ALPHA: f32 = 0.25;
+
+#[derive(Component, Default)]
+pub struct Door {
+    pub locked: bool,
+    pub open: bool,
+}
+
+impl Door {
+    pub fn from_field(entity_instance: &EntityInstance) -> Self {
+        Door {
+            locked: *entity_instance
+                .get_bool_field("locked")
+                .expect("Door should have locked field"),
+            open: false,
+        }
+    }
+}
+
+// Doors opened so far, levels are despawned when the player moves away and respawn with closed doors
+#[derive(Resource, Default, Debug)]
+pub struct OpenedDoors(pub HashSet<EntityIid>);
+
+fn sprite(entity_instance: &EntityInstance) -> Sprite {
+    Sprite::from_color(
+        DOOR_COLOR,
+        Vec2::new(entity_instance.width as f32, entity_instance.height as f32),
+    )
+}
+
+// Has to sit on a level edge, walking through selects the neighbour loaded behind it
+#[derive(Bundle, Default, LdtkEntity)]
+pub struct DoorBundle {
+    #[with(Door::from_field)]
+    pub door: Door,
+    #[with(sprite)]
+    pub sprite: Sprite,
+    #[from_entity_instance]
+    pub collider_bundle: ColliderBundle,
+    pub interactable: Interactable,
+    #[from_entity_instance]
+    pub entity_instance: EntityInstance,
+}
+
+fn restore_doors(mut doors: Query<(&EntityIid, &mut Door), Added<Door>>, opened: Res<OpenedDoors>) {
+    for (iid, mut door) in &mut doors {
+        if opened.0.contains(iid) {
+            door.locked = false;
+            door.open = true;
+        }
+    }
+}
+
+fn open_door(
+    mut interact_events: EventReader<Interact>,
+    mut doors: Query<(&EntityIid, &mut Door)>,
+    mut inventory: Single<&mut Inventory, With<Player>>,
+    mut opened: ResMut<OpenedDoors>,
+) {
+    for Interact(target) in interact_events.read() {
+        let Ok((iid, mut door)) = doors.get_mut(*target) else {
+            continue;
+        };
+        if door.open {
+            continue;
+        }
+
+        if door.locked {
+            if !inventory.take(Item::Key) {
+                info!("The door is locked");
+                continue;
+            }
+            door.locked = false;
+        }
+
+        door.open = true;
+        opened.0.insert(iid.clone());
+    }
+}
+
+fn door_state(mut cmd: Commands, mut doors: Query<(Entity, &Door, &mut Sprite), Changed<Door>>) {
+    for (entity, door, mut sprite) in &mut doors {
+        if door.open {
+            cmd.entity(entity).insert(ColliderDisabled);
+            sprite.color = DOOR_COLOR.with_alpha(DOOR_OPENED_ALPHA);
+            continue;
+        }
+
+        cmd.entity(entity).remove::<ColliderDisabled>();
+        sprite.color = match door.locked {
+            true => DOOR_LOCKED_COLOR,
+            false => DOOR_COLOR,
+        };
+    }
+}
+
+pub struct DoorPlugin;
+
+impl Plugin for DoorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_ldtk_entity::<DoorBundle>("Door")
+            .init_resource::<OpenedDoors>()
+            .add_systems(Update, (restore_doors, open_door, door_state).chain());
+    }
+}
@@ -1,41 +1,380 @@
+use std::collections::HashSet;
 use std::str::FromStr;
 
 use bevy::prelude::*;
+use bevy_aseprite_ultra::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
+use rand::distr::{weighted::WeightedIndex, Distribution};
 use rand::prelude::*;
-use yuyuko::YuyukoPlugin;
+use thiserror::Error;
 
-use crate::spell_card::SpellCard;
+use crate::bullet::cancel::{CancelArea, CancelBullets};
+use crate::colliders::SensorBundle;
+use crate::enemy::config::health::BOSS_DROP_POINTS;
+use crate::enemy::health::{Drops, Dying, Health, Hurtbox};
+use crate::spell_card::{
+    CaptureBonus, SpellCardCaptured, SpellCardDeclared, SpellCardExpired, SpellCardRegistry,
+};
 
-pub mod yuyuko;
+// LDtk tag of entities spawned as bosses, their sprite is "<lowercase identifier>.aseprite"
+const BOSS_TAG: &str = "Boss";
+
+#[derive(Event)]
+pub struct BossDefeated {
+    pub name: String,
+}
+
+// Bosses beaten so far, so they don't come back when their level respawns
+#[derive(Resource, Default, Debug)]
+pub struct DefeatedBosses(pub HashSet<EntityIid>);
+
+#[derive(Component, Default)]
+pub struct Boss {
+    pub name: String,
+}
+
+impl Boss {
+    pub fn from_field(entity_instance: &EntityInstance) -> Self {
+        Boss {
+            name: entity_instance.identifier.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("the given CardOrder doesn't exist")]
+pub struct NoSuchCardOrder;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum CardOrder {
+    #[default]
+    Random,
+    Fixed,
+    Weighted,
+}
+
+impl FromStr for CardOrder {
+    type Err = NoSuchCardOrder;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        use CardOrder::*;
+        match string {
+            "Random" => Ok(Random),
+            "Fixed" => Ok(Fixed),
+            "Weighted" => Ok(Weighted),
+            _ => Err(NoSuchCardOrder),
+        }
+    }
+}
 
 #[derive(Component, Default)]
-pub struct SpellCardList(Vec<SpellCard>);
+pub struct SpellCardList {
+    cards: Vec<String>,
+    weights: Vec<f32>,
+    order: CardOrder,
+}
 
 impl SpellCardList {
-    fn random(&mut self) -> Option<SpellCard> {
-        let i = (0..self.0.len()).choose(&mut rand::rng())?;
-        Some(self.0.swap_remove(i))
+    pub fn next_card(&mut self) -> Option<String> {
+        if self.cards.is_empty() {
+            return None;
+        }
+
+        let i = match self.order {
+            CardOrder::Fixed => 0,
+            CardOrder::Random => (0..self.cards.len()).choose(&mut rand::rng())?,
+            // Cards left with no weight at all are declared in order
+            CardOrder::Weighted => {
+                WeightedIndex::new(&self.weights).map_or(0, |index| index.sample(&mut rand::rng()))
+            }
+        };
+
+        self.weights.remove(i);
+        Some(self.cards.remove(i))
+    }
+
+    pub fn from_field(entity_instance: &EntityInstance) -> Self {
+        let cards = entity_instance
+            .get_maybe_enums_field("spell_cards")
+            .expect("Entity should have spell_cards field")
+            .iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let order = match entity_instance.get_maybe_enum_field("card_order") {
+            Ok(Some(order)) => CardOrder::from_str(order).unwrap_or_else(|error| {
+                let name = &entity_instance.identifier;
+                error!("{name}: {error} ({order}), falling back to Fixed");
+                CardOrder::Fixed
+            }),
+            _ => CardOrder::default(),
+        };
+
+        let weights = match entity_instance.get_maybe_floats_field("card_weights") {
+            Ok(weights) if weights.len() == cards.len() => {
+                weights.iter().map(|weight| weight.unwrap_or(1.)).collect()
+            }
+            _ => vec![1.; cards.len()],
+        };
+
+        SpellCardList {
+            cards,
+            weights,
+            order,
+        }
     }
+}
+
+// Drops cards missing from the registry, so typos in the level show up as soon as it's loaded
+fn validate_spell_cards(
+    mut bosses: Query<(&Boss, &mut SpellCardList), Added<SpellCardList>>,
+    registry: Res<SpellCardRegistry>,
+) {
+    for (Boss { name }, mut card_list) in &mut bosses {
+        let SpellCardList {
+            cards,
+            weights,
+            order,
+        } = card_list.as_mut();
+
+        // Every weight has to stay drawable, or the last cards could never be declared
+        if *order == CardOrder::Weighted && !weights.iter().all(|weight| *weight > 0.) {
+            error!("{name}: card weights should be positive, falling back to equal weights");
+            weights.fill(1.);
+        }
+
+        let mut i = 0;
+        while i < cards.len() {
+            if let Err(error) = registry.get(&cards[i]) {
+                error!("{name}: {error}");
+                cards.remove(i);
+                weights.remove(i);
+                continue;
+            }
+            i += 1;
+        }
+    }
+}
+
+// Spell card currently declared by the boss
+#[derive(Component, Default)]
+pub struct ActiveSpellCard(pub Option<Entity>);
 
+#[derive(Bundle, Default)]
+pub struct BossBundle {
+    pub boss: Boss,
+    pub sprite: Sprite,
+    pub animation: AseAnimation,
+    pub spell_card_list: SpellCardList,
+    pub active_card: ActiveSpellCard,
+    pub health: Health,
+    pub hurtbox: Hurtbox,
+    pub drops: Drops,
+    pub sensor_bundle: SensorBundle,
+    pub worldly: Worldly,
+}
+
+impl BossBundle {
     pub fn from_field(entity_instance: &EntityInstance) -> Self {
-        SpellCardList(
-            entity_instance
-                .get_maybe_enums_field("spell_cards")
-                .expect("Entity should have spell_cards field")
-                .iter()
-                .map(|card| {
-                    SpellCard::from_str(card.clone().unwrap().as_str())
-                        .expect("SpellCard with specified name should exist")
-                })
-                .collect::<Vec<_>>(),
-        )
+        BossBundle {
+            boss: Boss::from_field(entity_instance),
+            spell_card_list: SpellCardList::from_field(entity_instance),
+            hurtbox: entity_instance.into(),
+            drops: Drops {
+                points: BOSS_DROP_POINTS,
+            },
+            sensor_bundle: entity_instance.into(),
+            worldly: Worldly::from_entity_info(entity_instance),
+            ..default()
+        }
+    }
+}
+
+// Bosses aren't registered by identifier, any entity tagged with BOSS_TAG becomes one
+fn spawn_bosses(
+    mut cmd: Commands,
+    entities: Query<(Entity, &EntityInstance), Added<EntityInstance>>,
+) {
+    for (entity, entity_instance) in &entities {
+        if entity_instance.tags.iter().any(|tag| tag == BOSS_TAG) {
+            cmd.entity(entity)
+                .insert(BossBundle::from_field(entity_instance));
+        }
+    }
+}
+
+// Cancels the current card once it runs out of health or time and declares the next one
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn advance_cards(
+    mut bosses: Query<
+        (
+            Entity,
+            &EntityIid,
+            &Boss,
+            &mut SpellCardList,
+            &mut ActiveSpellCard,
+            &mut Health,
+            &Drops,
+            &GlobalTransform,
+        ),
+        Without<Dying>,
+    >,
+    mut expired_events: EventReader<SpellCardExpired>,
+    registry: Res<SpellCardRegistry>,
+    mut declared_event: EventWriter<SpellCardDeclared>,
+    mut cancel_event: EventWriter<CancelBullets>,
+    mut captured_event: EventWriter<SpellCardCaptured>,
+    mut defeated_event: EventWriter<BossDefeated>,
+    captures: Query<(&CaptureBonus, &Name)>,
+    mut defeated: ResMut<DefeatedBosses>,
+    mut cmd: Commands,
+) {
+    let expired = expired_events
+        .read()
+        .map(|SpellCardExpired(card)| *card)
+        .collect::<Vec<_>>();
+
+    for (boss, iid, Boss { name }, mut card_list, mut active_card, mut health, drops, transform) in
+        &mut bosses
+    {
+        match active_card.0 {
+            Some(card) if expired.contains(&card) => {}
+            Some(card) if health.is_empty() => {
+                if let Ok((CaptureBonus(true), title)) = captures.get(card) {
+                    info!("{title} captured!");
+                    captured_event.write(SpellCardCaptured);
+                }
+                cmd.entity(card).despawn();
+            }
+            Some(_) => continue,
+            None => {}
+        }
+
+        if active_card.0.is_some() {
+            cancel_event.write(CancelBullets {
+                area: CancelArea::Screen,
+                items: true,
+            });
+        }
+
+        let Some(card) = card_list.next_card() else {
+            info!("{name} defeated!");
+            defeated_event.write(BossDefeated { name: name.clone() });
+            defeated.0.insert(iid.clone());
+            drops.spawn(&mut cmd, transform.translation());
+            active_card.0 = None;
+            cmd.entity(boss)
+                .remove::<Hurtbox>()
+                .insert(Dying::default());
+            continue;
+        };
+
+        let entry = match registry.get(&card) {
+            Ok(entry) => entry,
+            Err(error) => {
+                error!("{name}: {error}");
+                active_card.0 = None;
+                continue;
+            }
+        };
+
+        info!("{name} declared {}", entry.title);
+        health.reset(entry.health);
+        let mut card = cmd.spawn((CaptureBonus::default(), ChildOf(boss)));
+        (entry.spawn)(&mut card, entry);
+        active_card.0 = Some(card.id());
+        declared_event.write(SpellCardDeclared {
+            card: card.id(),
+            title: entry.title,
+        });
+    }
+}
+
+fn skip_defeated(
+    mut cmd: Commands,
+    bosses: Query<(Entity, &EntityIid), Added<Boss>>,
+    defeated: Res<DefeatedBosses>,
+) {
+    for (boss, iid) in &bosses {
+        if defeated.0.contains(iid) {
+            cmd.entity(boss).despawn();
+        }
+    }
+}
+
+fn animation(mut bosses: Query<(&Boss, &mut AseAnimation), Added<Boss>>, server: Res<AssetServer>) {
+    for (Boss { name }, mut animation) in &mut bosses {
+        animation.aseprite = server.load(format!("{}.aseprite", name.to_lowercase()));
+        animation.animation = Animation::tag("idle");
     }
 }
+
 pub struct BossPlugin;
 
 impl Plugin for BossPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(YuyukoPlugin);
+        app.add_event::<BossDefeated>()
+            .init_resource::<DefeatedBosses>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        spawn_bosses,
+                        skip_defeated,
+                        validate_spell_cards,
+                        advance_cards,
+                    )
+                        .chain(),
+                    animation,
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card_list(order: CardOrder, weights: &[f32]) -> SpellCardList {
+        SpellCardList {
+            cards: ["a", "b", "c"].map(String::from).to_vec(),
+            weights: weights.to_vec(),
+            order,
+        }
+    }
+
+    fn declared(mut card_list: SpellCardList) -> Vec<String> {
+        std::iter::from_fn(|| card_list.next_card()).collect()
+    }
+
+    #[test]
+    fn spell_cards_fixed() {
+        let card_list = card_list(CardOrder::Fixed, &[1., 1., 1.]);
+        assert_eq!(declared(card_list), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn spell_cards_random() {
+        for _ in 0..16 {
+            let mut cards = declared(card_list(CardOrder::Random, &[1., 1., 1.]));
+            cards.sort();
+            assert_eq!(cards, ["a", "b", "c"]);
+        }
+    }
+
+    #[test]
+    fn spell_cards_weighted() {
+        for _ in 0..16 {
+            let mut cards = declared(card_list(CardOrder::Weighted, &[1., 5., 0.5]));
+            cards.sort();
+            assert_eq!(cards, ["a", "b", "c"]);
+        }
+    }
+
+    #[test]
+    fn spell_cards_zero_weights() {
+        let card_list = card_list(CardOrder::Weighted, &[0., 1., 0.]);
+        assert_eq!(declared(card_list), ["b", "a", "c"]);
     }
 }
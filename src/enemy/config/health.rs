@@ -0,0 +1,12 @@
+// Used when an enemy has no "hp" field in the level
+pub const ENEMY_HEALTH: i64 = 5;
+
+pub const ENEMY_DEATH_TIME: f32 = 0.4;
+// Game time, so it lasts through the hit-stop
+pub const ENEMY_KNOCKBACK_TIME: f32 = 0.25;
+pub const ENEMY_DEATH_SCALE: f32 = 1.5;
+
+pub const ENEMY_DROP_POINTS: u32 = 5;
+pub const BOSS_DROP_POINTS: u32 = 40;
+// Drops are scattered around the enemy's center
+pub const ENEMY_DROP_SPREAD: f32 = 12.;
@@ -0,0 +1,17 @@
+use bevy::color::Color;
+
+pub const MOB_COLOR: Color = Color::srgb(0.75, 0.3, 0.35);
+
+// Use without deltatime
+pub const MOB_SPEED: f32 = 40.;
+// Below this the mob is considered stuck against a wall
+pub const MOB_STUCK_SPEED: f32 = 1.;
+pub const MOB_PATROL_TOLERANCE: f32 = 2.;
+
+// Use with deltatime
+pub const MOB_GRAVITY: f64 = 830.;
+
+pub const MOB_CONTACT_DAMAGE: i64 = 1;
+
+// Used when a mob carrying a turret has no "shoot_delay" field
+pub const MOB_SHOOT_DELAY: f32 = 2.;
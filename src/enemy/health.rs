@@ -0,0 +1,216 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+use rand::prelude::*;
+
+use super::boss::Boss;
+use super::config::health::*;
+use super::spawner::turret::Turret;
+use super::ShootTimer;
+use crate::bullet::cancel::PointItem;
+
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct Health {
+    pub current: i64,
+    pub max: i64,
+}
+
+impl Health {
+    pub fn new(max: i64) -> Self {
+        Health { current: max, max }
+    }
+
+    pub fn reset(&mut self, max: i64) {
+        *self = Health::new(max);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.current <= 0
+    }
+
+    pub fn from_field(entity_instance: &EntityInstance) -> Self {
+        let max = match entity_instance.get_int_field("hp") {
+            Ok(hp) => *hp as i64,
+            Err(_) => {
+                warn!(
+                    "{} has no hp field, falling back to {ENEMY_HEALTH}",
+                    entity_instance.identifier
+                );
+                ENEMY_HEALTH
+            }
+        };
+        Health::new(max)
+    }
+}
+
+// Area the player's attacks can hit
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Hurtbox {
+    pub half_size: Vec2,
+}
+
+impl Hurtbox {
+    pub fn rect(&self, center: Vec2) -> Rect {
+        Rect::from_center_half_size(center, self.half_size)
+    }
+}
+
+impl From<&EntityInstance> for Hurtbox {
+    fn from(entity_instance: &EntityInstance) -> Self {
+        Hurtbox {
+            half_size: Vec2::new(entity_instance.width as f32, entity_instance.height as f32) / 2.,
+        }
+    }
+}
+
+#[derive(Event, Debug)]
+pub struct Damage {
+    pub target: Entity,
+    pub amount: i64,
+}
+
+// Keeps enemies from steering while they're pushed back by a hit
+#[derive(Component)]
+pub struct Knockback(Timer);
+
+impl Default for Knockback {
+    fn default() -> Self {
+        Knockback(Timer::from_seconds(ENEMY_KNOCKBACK_TIME, TimerMode::Once))
+    }
+}
+
+#[derive(Event, Debug)]
+pub struct EnemyKilled {
+    pub enemy: Entity,
+    pub position: Vec2,
+}
+
+// Point items left behind on death
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Drops {
+    pub points: u32,
+}
+
+impl Default for Drops {
+    fn default() -> Self {
+        Drops {
+            points: ENEMY_DROP_POINTS,
+        }
+    }
+}
+
+impl Drops {
+    pub fn spawn(&self, cmd: &mut Commands, position: Vec3) {
+        let mut rng = rand::rng();
+        for _ in 0..self.points {
+            let offset = Vec2::new(
+                rng.random_range(-ENEMY_DROP_SPREAD..=ENEMY_DROP_SPREAD),
+                rng.random_range(-ENEMY_DROP_SPREAD..=ENEMY_DROP_SPREAD),
+            );
+            cmd.spawn(PointItem::bundle(position + offset.extend(0.)));
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Dying(Timer);
+
+impl Default for Dying {
+    fn default() -> Self {
+        Dying(Timer::from_seconds(ENEMY_DEATH_TIME, TimerMode::Once))
+    }
+}
+
+fn apply_damage(mut damage_events: EventReader<Damage>, mut targets: Query<&mut Health>) {
+    for Damage { target, amount } in damage_events.read() {
+        let Ok(mut health) = targets.get_mut(*target) else {
+            continue;
+        };
+        health.current -= amount;
+    }
+}
+
+// Bosses are left out, their health is refilled for every spell card and they die with the last one
+#[allow(clippy::type_complexity)]
+fn check_death(
+    mut cmd: Commands,
+    enemies: Query<
+        (
+            Entity,
+            &Health,
+            &GlobalTransform,
+            Option<&Drops>,
+            Option<&Children>,
+        ),
+        (Changed<Health>, Without<Dying>, Without<Boss>),
+    >,
+    turrets: Query<(), With<Turret>>,
+    mut killed_event: EventWriter<EnemyKilled>,
+) {
+    for (enemy, health, transform, drops, children) in &enemies {
+        if !health.is_empty() {
+            continue;
+        }
+
+        // Turrets take their patterns and lasers with them
+        for child in children.into_iter().flatten() {
+            if turrets.contains(*child) {
+                cmd.entity(*child).despawn();
+            }
+        }
+
+        let position = transform.translation();
+        if let Some(drops) = drops {
+            drops.spawn(&mut cmd, position);
+        }
+
+        // Standalone turrets stop shooting while they fade out
+        cmd.entity(enemy)
+            .remove::<(Hurtbox, ShootTimer)>()
+            .insert(Dying::default());
+        killed_event.write(EnemyKilled {
+            enemy,
+            position: position.truncate(),
+        });
+    }
+}
+
+fn knockback(mut cmd: Commands, mut enemies: Query<(Entity, &mut Knockback)>, time: Res<Time>) {
+    for (enemy, mut timer) in &mut enemies {
+        if timer.0.tick(time.delta()).finished() {
+            cmd.entity(enemy).remove::<Knockback>();
+        }
+    }
+}
+
+// Swells and fades out before despawning
+fn dying(
+    mut cmd: Commands,
+    mut enemies: Query<(Entity, &mut Dying, Option<&mut Sprite>, &mut Transform)>,
+    time: Res<Time>,
+) {
+    for (enemy, mut timer, sprite, mut transform) in &mut enemies {
+        if timer.0.tick(time.delta()).finished() {
+            cmd.entity(enemy).despawn();
+            continue;
+        }
+
+        let left = timer.0.fraction_remaining();
+        if let Some(mut sprite) = sprite {
+            sprite.color = sprite.color.with_alpha(left);
+        }
+        transform.scale = Vec3::splat(ENEMY_DEATH_SCALE.lerp(1., left));
+    }
+}
+
+pub struct HealthPlugin;
+
+impl Plugin for HealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Damage>()
+            .add_event::<EnemyKilled>()
+            .add_systems(
+                Update,
+                ((apply_damage, check_death).chain(), knockback, dying),
+            );
+    }
+}
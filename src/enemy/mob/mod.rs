@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+
+use crate::{
+    colliders::ColliderBundle,
+    enemy::{
+        config::mob::*,
+        health::{Drops, Health, Hurtbox},
+        spawner::turret::{TurretAmmoList, TurretAmmoSource, TurretBundle},
+    },
+    ground_detection::GroundDetection,
+    item::Loot,
+    player::{
+        hitbox::PlayerHitbox,
+        stats::{ChangeHealth, InvincibilityTimer},
+        Player,
+    },
+};
+
+pub mod patrol;
+use patrol::{Patrol, PatrolPlugin};
+
+#[derive(Component, Default)]
+pub struct Mob;
+
+fn sprite(entity_instance: &EntityInstance) -> Sprite {
+    Sprite::from_color(
+        MOB_COLOR,
+        Vec2::new(entity_instance.width as f32, entity_instance.height as f32),
+    )
+}
+
+#[derive(Bundle, Default, LdtkEntity)]
+pub struct MobBundle {
+    pub entity: Mob,
+    #[with(sprite)]
+    pub sprite: Sprite,
+    #[ldtk_entity]
+    pub patrol: Patrol,
+    #[with(Health::from_field)]
+    pub health: Health,
+    #[from_entity_instance]
+    pub hurtbox: Hurtbox,
+    pub drops: Drops,
+    #[with(Loot::from_field)]
+    pub loot: Loot,
+    #[from_entity_instance]
+    pub collider_bundle: ColliderBundle,
+    pub ground_detection: GroundDetection,
+    #[from_entity_instance]
+    pub entity_instance: EntityInstance,
+}
+
+// Mobs with a "pattern" field carry a turret shooting it
+fn setup(
+    mobs: Query<(Entity, &EntityInstance), Added<Mob>>,
+    server: Res<AssetServer>,
+    mut cmd: Commands,
+) {
+    for (mob, ldtk_entity) in &mobs {
+        let Ok(Some(pattern)) = ldtk_entity.get_maybe_string_field("pattern") else {
+            continue;
+        };
+
+        let shoot_delay = match ldtk_entity.get_float_field("shoot_delay") {
+            Ok(delay) => *delay,
+            Err(_) => MOB_SHOOT_DELAY,
+        };
+        cmd.spawn((
+            TurretBundle::from_params(
+                TurretAmmoList::default(),
+                Duration::from_secs_f32(shoot_delay),
+                Duration::ZERO,
+            ),
+            TurretAmmoSource::new(server.load(pattern.clone())),
+        ))
+        .insert(ChildOf(mob));
+    }
+}
+
+// Touching a mob hurts once per invincibility window, not every frame
+fn contact_damage(
+    mobs: Query<(&GlobalTransform, &Hurtbox), With<Mob>>,
+    player: Single<(&GlobalTransform, &PlayerHitbox), With<Player>>,
+    invincibility: Res<InvincibilityTimer>,
+    mut health_event: EventWriter<ChangeHealth>,
+) {
+    if invincibility.is_active() {
+        return;
+    }
+
+    let (player_transform, hitbox) = *player;
+    let hitbox = hitbox.rect(player_transform.translation().truncate());
+
+    if mobs.iter().any(|(transform, hurtbox)| {
+        !hurtbox
+            .rect(transform.translation().truncate())
+            .intersect(hitbox)
+            .is_empty()
+    }) {
+        health_event.write(ChangeHealth(-MOB_CONTACT_DAMAGE));
+    }
+}
+
+pub struct MobPlugin;
+
+impl Plugin for MobPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_ldtk_entity::<MobBundle>("Mob")
+            .add_plugins(PatrolPlugin)
+            .add_systems(Update, (setup, contact_damage));
+    }
+}
@@ -0,0 +1,185 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::{prelude::*, utils::ldtk_pixel_coords_to_translation_pivoted};
+use bevy_rapier2d::prelude::Velocity;
+
+use super::Mob;
+use crate::enemy::config::mob::*;
+use crate::enemy::health::{Dying, Knockback};
+use crate::ground_detection::GroundDetection;
+
+// Points walked back and forth, the first one is where the mob was placed
+#[derive(Component, Debug, Default, Clone)]
+pub struct Patrol {
+    pub points: Vec<Vec2>,
+    index: usize,
+    forward: bool,
+    walking: bool,
+}
+
+impl Patrol {
+    fn target(&self) -> Option<Vec2> {
+        match self.points.len() {
+            0 | 1 => None,
+            _ => self.points.get(self.index).copied(),
+        }
+    }
+
+    fn advance(&mut self) {
+        if self.index == 0 {
+            self.forward = true;
+        } else if self.index == self.points.len() - 1 {
+            self.forward = false;
+        }
+
+        self.index = match self.forward {
+            true => self.index + 1,
+            false => self.index - 1,
+        };
+    }
+
+    fn turn_around(&mut self) {
+        self.forward = !self.forward;
+        self.index = match self.forward {
+            true => (self.index + 1).min(self.points.len() - 1),
+            false => self.index.saturating_sub(1),
+        };
+    }
+}
+
+impl LdtkEntity for Patrol {
+    fn bundle_entity(
+        entity_instance: &EntityInstance,
+        layer_instance: &LayerInstance,
+        _: Option<&Handle<Image>>,
+        _: Option<&TilesetDefinition>,
+        _: &AssetServer,
+        _: &mut Assets<TextureAtlasLayout>,
+    ) -> Self {
+        let level_height = layer_instance.c_hei * layer_instance.grid_size;
+        let size = IVec2::new(entity_instance.width, entity_instance.height);
+        let to_translation = |pixel_coords: IVec2| {
+            ldtk_pixel_coords_to_translation_pivoted(
+                pixel_coords,
+                level_height,
+                size,
+                entity_instance.pivot,
+            )
+        };
+
+        let mut points = vec![to_translation(entity_instance.px)];
+        points.extend(
+            entity_instance
+                .iter_points_field("patrol")
+                .expect("Mob should have patrol field")
+                .map(|point| {
+                    // Points are grid cells, the mob stands at the bottom middle of them
+                    let pixel_coords =
+                        (point.as_vec2() + Vec2::new(0.5, 1.)) * layer_instance.grid_size as f32;
+                    to_translation(pixel_coords.as_ivec2())
+                }),
+        );
+
+        Patrol {
+            points,
+            index: 1,
+            forward: true,
+            walking: false,
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn patrol(
+    mut mobs: Query<
+        (
+            &mut Patrol,
+            &mut Velocity,
+            &Transform,
+            &GroundDetection,
+            Has<Knockback>,
+        ),
+        (With<Mob>, Without<Dying>),
+    >,
+    time: Res<Time>,
+) {
+    let delta = time.delta_secs_f64();
+
+    for (mut patrol, mut velocity, transform, GroundDetection { grounded, .. }, knocked_back) in
+        &mut mobs
+    {
+        velocity.linvel.y -= (MOB_GRAVITY * delta) as f32;
+
+        // Falling or knocked back mobs don't steer
+        if !grounded || knocked_back {
+            patrol.walking = false;
+            continue;
+        }
+
+        let Some(target) = patrol.target() else {
+            velocity.linvel.x = 0.;
+            continue;
+        };
+
+        if patrol.walking && velocity.linvel.x.abs() < MOB_STUCK_SPEED {
+            patrol.turn_around();
+        } else if (target.x - transform.translation.x).abs() <= MOB_PATROL_TOLERANCE {
+            patrol.advance();
+        }
+
+        let Some(target) = patrol.target() else {
+            continue;
+        };
+        velocity.linvel.x = (target.x - transform.translation.x).signum() * MOB_SPEED;
+        patrol.walking = true;
+    }
+}
+
+pub struct PatrolPlugin;
+
+impl Plugin for PatrolPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, patrol);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patrol(points: usize) -> Patrol {
+        Patrol {
+            points: (0..points).map(|x| Vec2::new(x as f32, 0.)).collect(),
+            index: 1,
+            forward: true,
+            walking: false,
+        }
+    }
+
+    #[test]
+    fn patrol_back_and_forth() {
+        let mut patrol = patrol(3);
+        let mut targets = vec![patrol.target()];
+        for _ in 0..5 {
+            patrol.advance();
+            targets.push(patrol.target());
+        }
+
+        let expected = [1., 2., 1., 0., 1., 2.].map(|x| Some(Vec2::new(x, 0.)));
+        assert_eq!(targets, expected);
+    }
+
+    #[test]
+    fn patrol_turn_around() {
+        let mut patrol = patrol(3);
+        patrol.advance();
+        patrol.turn_around();
+        assert_eq!(patrol.target(), Some(Vec2::new(1., 0.)));
+        patrol.advance();
+        assert_eq!(patrol.target(), Some(Vec2::new(0., 0.)));
+    }
+
+    #[test]
+    fn patrol_single_point() {
+        assert_eq!(patrol(1).target(), None);
+    }
+}
@@ -1,10 +1,15 @@
 use bevy::prelude::*;
 use boss::BossPlugin;
+use health::HealthPlugin;
+use mob::MobPlugin;
 use spawner::SpawnerPlugin;
 use std::time::Duration;
 use still::StillEnemyPlugin;
 
 pub mod boss;
+mod config;
+pub mod health;
+pub mod mob;
 pub mod spawner;
 pub mod still;
 
@@ -25,6 +30,8 @@ impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(SpawnerPlugin)
             .add_plugins(BossPlugin)
+            .add_plugins(HealthPlugin)
+            .add_plugins(MobPlugin)
             .add_plugins(StillEnemyPlugin);
     }
 }
@@ -1,10 +1,17 @@
 use bevy::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
+use serde::Deserialize;
 use std::time::Duration;
 
 use super::super::ShootTimer;
 use crate::{
-    bullet_pattern::{construction::ConstructionType, PatternBundle, PatternParams},
+    bullet_pattern::{
+        asset::{PatternAsset, PatternSource},
+        construction::ConstructionType,
+        PatternBundle, PatternParams,
+    },
+    enemy::health::{Drops, Health, Hurtbox},
+    laser::{LaserBundle, LaserParams},
     physics::movement::MovementType,
     player::Player,
 };
@@ -12,12 +19,15 @@ use crate::{
 #[derive(Component, Default)]
 pub struct Turret;
 
-#[derive(Component, Default, Clone)]
-pub struct TurretAmmo {
-    pub params: PatternParams,
-    pub construction: ConstructionType,
-    pub speed: f32,
-    pub accel: f32,
+#[derive(Component, Clone, Debug, Deserialize)]
+pub enum TurretAmmo {
+    Pattern {
+        params: PatternParams,
+        construction: ConstructionType,
+        speed: f32,
+        accel: f32,
+    },
+    Laser(LaserParams),
 }
 
 #[derive(Component, Default, Clone)]
@@ -32,6 +42,54 @@ impl TurretAmmoList {
     }
 }
 
+/// Fills the turret's `TurretAmmoList` from a `.pattern.ron` file once it's loaded
+#[derive(Component, Default, Clone)]
+pub struct TurretAmmoSource {
+    pub handle: Handle<PatternAsset>,
+    pub speed: Option<f32>,
+    pub accel: Option<f32>,
+}
+
+impl TurretAmmoSource {
+    pub fn new(handle: Handle<PatternAsset>) -> Self {
+        TurretAmmoSource {
+            handle,
+            ..default()
+        }
+    }
+
+    /// Overrides speed and acceleration of every pattern in the file, lasers are left as is
+    pub fn with_speed(mut self, speed: f32, accel: f32) -> Self {
+        self.speed = Some(speed);
+        self.accel = Some(accel);
+        self
+    }
+
+    fn ammo_list(&self, asset: &PatternAsset) -> TurretAmmoList {
+        TurretAmmoList::new(
+            asset
+                .ammo
+                .iter()
+                .cloned()
+                .map(|ammo| match ammo {
+                    TurretAmmo::Pattern {
+                        params,
+                        construction,
+                        speed,
+                        accel,
+                    } => TurretAmmo::Pattern {
+                        params,
+                        construction,
+                        speed: self.speed.unwrap_or(speed),
+                        accel: self.accel.unwrap_or(accel),
+                    },
+                    laser => laser,
+                })
+                .collect(),
+        )
+    }
+}
+
 #[derive(Bundle, Default, LdtkEntity)]
 pub struct TurretBundle {
     pub name: Name,
@@ -56,6 +114,60 @@ impl TurretBundle {
     }
 }
 
+// Turrets placed on their own in the level, unlike the ones carried by enemies, can be destroyed
+#[derive(Bundle, Default, LdtkEntity)]
+pub struct LdtkTurretBundle {
+    #[ldtk_entity]
+    pub turret: TurretBundle,
+    #[with(Health::from_field)]
+    pub health: Health,
+    #[from_entity_instance]
+    pub hurtbox: Hurtbox,
+    pub drops: Drops,
+}
+
+fn load_turret_ammo(
+    mut turrets: Query<(&TurretAmmoSource, &mut TurretAmmoList), With<Turret>>,
+    patterns: Res<Assets<PatternAsset>>,
+) {
+    for (source, mut ammo) in &mut turrets {
+        if !ammo.list.is_empty() {
+            continue;
+        }
+
+        if let Some(pattern) = patterns.get(&source.handle) {
+            *ammo = source.ammo_list(pattern);
+        }
+    }
+}
+
+fn reload_turret_ammo(
+    mut turrets: Query<(&TurretAmmoSource, &mut TurretAmmoList), With<Turret>>,
+    mut events: EventReader<AssetEvent<PatternAsset>>,
+    patterns: Res<Assets<PatternAsset>>,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        let Some(pattern) = patterns.get(*id) else {
+            continue;
+        };
+
+        for (source, mut ammo) in &mut turrets {
+            if source.handle.id() != *id {
+                continue;
+            }
+
+            // Keep the position in the list, so the turret carries on where it stopped
+            let i = ammo.i;
+            *ammo = source.ammo_list(pattern);
+            ammo.i = i;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
 fn turret_shoot(
     player: Single<&Transform, With<Player>>,
     mut turrets: Query<
@@ -63,6 +175,7 @@ fn turret_shoot(
             &mut ShootTimer,
             &GlobalTransform,
             &mut TurretAmmoList,
+            Option<&TurretAmmoSource>,
             Entity,
         ),
         With<Turret>,
@@ -72,22 +185,37 @@ fn turret_shoot(
 ) {
     let player_position = player.translation.truncate();
 
-    for (mut shoot_timer, transform, mut ammo, turret) in &mut turrets {
+    for (mut shoot_timer, transform, mut ammo, source, turret) in &mut turrets {
         if shoot_timer.0.tick(time.delta()).just_finished() {
+            if ammo.list.is_empty() {
+                continue;
+            }
+
             ammo.i += 1;
 
             if ammo.i >= ammo.list.len() {
                 ammo.i = 0;
             }
 
-            let params = ammo.list[ammo.i].params.clone();
-            let construction = ammo.list[ammo.i].construction.clone();
-            let speed = ammo.list[ammo.i].speed;
-            let accel = ammo.list[ammo.i].accel;
-
             let mut dir_to_player = player_position - transform.translation().truncate();
             dir_to_player = dir_to_player / dir_to_player.length();
 
+            let (params, construction, speed, accel) = match ammo.list[ammo.i].clone() {
+                TurretAmmo::Pattern {
+                    params,
+                    construction,
+                    speed,
+                    accel,
+                } => (params, construction, speed, accel),
+                TurretAmmo::Laser(params) => {
+                    let laser = cmd
+                        .spawn(LaserBundle::new(params, dir_to_player.to_angle()))
+                        .id();
+                    cmd.entity(turret).add_child(laser);
+                    continue;
+                }
+            };
+
             let pattern = cmd
                 .spawn(PatternBundle::new(
                     params,
@@ -99,6 +227,13 @@ fn turret_shoot(
                 ))
                 .id();
 
+            if let Some(source) = source {
+                cmd.entity(pattern).insert(PatternSource {
+                    handle: source.handle.clone(),
+                    index: ammo.i,
+                });
+            }
+
             cmd.entity(turret).add_child(pattern);
         }
     }
@@ -108,7 +243,10 @@ pub struct TurretPlugin;
 
 impl Plugin for TurretPlugin {
     fn build(&self, app: &mut App) {
-        app.register_ldtk_entity::<TurretBundle>("Turret")
-            .add_systems(Update, turret_shoot);
+        app.register_ldtk_entity::<LdtkTurretBundle>("Turret")
+            .add_systems(
+                Update,
+                (load_turret_ammo, reload_turret_ammo, turret_shoot).chain(),
+            );
     }
 }
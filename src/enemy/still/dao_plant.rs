@@ -1,10 +1,11 @@
 use std::time::Duration;
 
 use crate::{
-    bullet_pattern::{construction::ConstructionType, PatternParams},
     colliders::SensorBundle,
-    enemy::spawner::turret::{TurretAmmo, TurretAmmoList, TurretBundle},
-    physics::movement::MovementType,
+    enemy::{
+        health::{Drops, Health, Hurtbox},
+        spawner::turret::{TurretAmmoList, TurretAmmoSource, TurretBundle},
+    },
 };
 use bevy::prelude::*;
 use bevy_aseprite_ultra::prelude::*;
@@ -18,6 +19,11 @@ pub struct DaoPlantBundle {
     pub entity: DaoPlant,
     pub sprite: Sprite,
     pub animation: AseAnimation,
+    #[with(Health::from_field)]
+    pub health: Health,
+    #[from_entity_instance]
+    pub hurtbox: Hurtbox,
+    pub drops: Drops,
     #[from_entity_instance]
     pub sensor_bundle: SensorBundle,
     #[worldly]
@@ -26,23 +32,22 @@ pub struct DaoPlantBundle {
     pub entity_instance: EntityInstance,
 }
 
-fn setup(plants: Query<(Entity, &EntityInstance), Added<DaoPlant>>, mut cmd: Commands) {
+const PATTERN_PATH: &str = "patterns/dao_plant.pattern.ron";
+
+fn setup(
+    plants: Query<(Entity, &EntityInstance), Added<DaoPlant>>,
+    server: Res<AssetServer>,
+    mut cmd: Commands,
+) {
     for (plant, ldtk_entity) in &plants {
-        let ammo = TurretAmmoList::new(vec![TurretAmmo {
-            params: PatternParams {
-                scale: 0.,
-                bullet_amount: 1,
-                bullet_movement: MovementType::Still,
-                construction_frequency: Duration::from_secs_f64(0.),
-            },
-            construction: ConstructionType::Circle,
-            speed: *ldtk_entity
+        let ammo = TurretAmmoSource::new(server.load(PATTERN_PATH)).with_speed(
+            *ldtk_entity
                 .get_float_field("bullet_speed")
                 .expect("DaoPlant should have bullet_speed field"),
-            accel: *ldtk_entity
+            *ldtk_entity
                 .get_float_field("bullet_accel")
                 .expect("DaoPlant should have bullet_accel field"),
-        }]);
+        );
 
         let shoot_delay = *ldtk_entity
             .get_float_field("shoot_delay")
@@ -50,10 +55,13 @@ fn setup(plants: Query<(Entity, &EntityInstance), Added<DaoPlant>>, mut cmd: Com
         let shoot_phase = *ldtk_entity
             .get_float_field("shoot_phase")
             .expect("DaoPlant should have shoot_offset field");
-        cmd.spawn(TurretBundle::from_params(
+        cmd.spawn((
+            TurretBundle::from_params(
+                TurretAmmoList::default(),
+                Duration::from_secs_f32(shoot_delay),
+                Duration::from_secs_f32(shoot_phase),
+            ),
             ammo,
-            Duration::from_secs_f32(shoot_delay),
-            Duration::from_secs_f32(shoot_phase),
         ))
         .insert(ChildOf(plant));
     }
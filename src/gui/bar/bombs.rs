@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::player::{stats::PlayerStats, Player};
+
+#[derive(Component, Default)]
+pub struct BombBar;
+
+#[derive(Bundle, Default)]
+pub struct BombBarBundle {
+    pub entity: BombBar,
+    pub transform: Transform,
+    pub color: BackgroundColor,
+    pub node: Node,
+    pub text: Text,
+}
+
+fn update(
+    mut bomb_bars: Query<&mut Text, With<BombBar>>,
+    player: Single<Ref<PlayerStats>, With<Player>>,
+) {
+    if !player.is_changed() {
+        return;
+    };
+
+    for mut text in &mut bomb_bars {
+        **text = player.bombs.to_string();
+    }
+}
+
+pub struct BombBarPlugin;
+
+impl Plugin for BombBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update);
+    }
+}
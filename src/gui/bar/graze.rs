@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use crate::player::graze::{Graze, GrazeCount};
+
+#[derive(Component, Default)]
+pub struct GrazeBar;
+
+#[derive(Bundle, Default)]
+pub struct GrazeBarBundle {
+    pub entity: GrazeBar,
+    pub transform: Transform,
+    pub color: BackgroundColor,
+    pub node: Node,
+    pub text: Text,
+}
+
+fn update(
+    mut graze_bars: Query<&mut Text, With<GrazeBar>>,
+    graze_events: EventReader<Graze>,
+    count: Res<GrazeCount>,
+) {
+    if graze_events.is_empty() {
+        return;
+    };
+
+    for mut text in &mut graze_bars {
+        **text = count.0.to_string();
+    }
+}
+
+pub struct GrazeBarPlugin;
+
+impl Plugin for GrazeBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update);
+    }
+}
@@ -1,9 +1,6 @@
 use bevy::prelude::*;
 
-use crate::player::{
-    stats::{ChangeHealth, PlayerStats},
-    Player,
-};
+use crate::player::{stats::PlayerStats, Player};
 
 #[derive(Component, Default)]
 pub struct HealthBar;
@@ -19,14 +16,13 @@ pub struct HealthBarBundle {
 
 fn update_health_bar(
     mut health_bars: Query<&mut Text, With<HealthBar>>,
-    health_events: EventReader<ChangeHealth>,
-    player: Single<&PlayerStats, With<Player>>,
+    player: Single<Ref<PlayerStats>, With<Player>>,
 ) {
-    if health_events.is_empty() {
+    if !player.is_changed() {
         return;
     };
 
-    let PlayerStats { health, .. } = player.into_inner();
+    let PlayerStats { health, .. } = &*player;
 
     for mut text in &mut health_bars {
         **text = health.to_string();
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::player::{stats::PlayerStats, Player};
+
+#[derive(Component, Default)]
+pub struct LivesBar;
+
+#[derive(Bundle, Default)]
+pub struct LivesBarBundle {
+    pub entity: LivesBar,
+    pub transform: Transform,
+    pub color: BackgroundColor,
+    pub node: Node,
+    pub text: Text,
+}
+
+fn update(
+    mut lives_bars: Query<&mut Text, With<LivesBar>>,
+    player: Single<Ref<PlayerStats>, With<Player>>,
+) {
+    if !player.is_changed() {
+        return;
+    };
+
+    for mut text in &mut lives_bars {
+        **text = player.lives.to_string();
+    }
+}
+
+pub struct LivesBarPlugin;
+
+impl Plugin for LivesBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update);
+    }
+}
@@ -1,8 +1,14 @@
 use bevy::prelude::*;
+use bombs::BombBarPlugin;
+use graze::GrazeBarPlugin;
 use health::HealthBarPlugin;
+use lives::LivesBarPlugin;
 use stamina::StaminaBarPlugin;
 
+pub mod bombs;
+pub mod graze;
 pub mod health;
+pub mod lives;
 pub mod stamina;
 
 pub struct StatusBarPlugin;
@@ -10,6 +16,9 @@ pub struct StatusBarPlugin;
 impl Plugin for StatusBarPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(HealthBarPlugin)
-            .add_plugins(StaminaBarPlugin);
+            .add_plugins(StaminaBarPlugin)
+            .add_plugins(GrazeBarPlugin)
+            .add_plugins(LivesBarPlugin)
+            .add_plugins(BombBarPlugin);
     }
 }
@@ -1,15 +1,25 @@
+use crate::score::{HiScore, Score};
 use crate::state::AppState;
 use bevy::prelude::*;
 
 pub mod bar;
-use bar::{health::HealthBarBundle, stamina::StaminaBarBundle, StatusBarPlugin};
+use bar::{
+    bombs::BombBarBundle, graze::GrazeBarBundle, health::HealthBarBundle, lives::LivesBarBundle,
+    stamina::StaminaBarBundle, StatusBarPlugin,
+};
 
 pub mod gui_parts;
 use gui_parts::*;
 
-use crate::player::config::stats::{PLAYER_HEALTH, PLAYER_STAMINA};
+pub mod spell_card;
+use spell_card::{SpellCardBannerBundle, SpellCardGuiPlugin};
 
-fn setup_gui(mut cmd: Commands) {
+pub mod score;
+use score::{HiScoreBundle, ScoreBundle, ScoreGuiPlugin};
+
+use crate::player::config::stats::{PLAYER_BOMBS, PLAYER_HEALTH, PLAYER_LIVES, PLAYER_STAMINA};
+
+fn setup_gui(mut cmd: Commands, score: Res<Score>, hi_score: Res<HiScore>) {
     cmd.spawn(GuiLeftBundle::default()).with_children(|parent| {
         parent.spawn(HealthBarBundle {
             node: Node {
@@ -25,9 +35,39 @@ fn setup_gui(mut cmd: Commands) {
             text: Text::new(PLAYER_HEALTH.to_string()),
             ..default()
         });
+        parent.spawn(LivesBarBundle {
+            node: Node {
+                width: Val::Px(27.0),
+                height: Val::Px(23.0),
+                align_self: AlignSelf::End,
+                justify_self: JustifySelf::Start,
+                padding: UiRect::all(Val::Px(8.0)),
+                margin: UiRect::px(0.0, 10.0, 10.0, 10.0),
+                ..default()
+            },
+            color: BackgroundColor(Color::srgba(0.45, 0.1, 0.2, 0.65)),
+            text: Text::new(PLAYER_LIVES.to_string()),
+            ..default()
+        });
+        parent.spawn(ScoreBundle {
+            node: Node {
+                height: Val::Px(23.0),
+                align_self: AlignSelf::Start,
+                padding: UiRect::all(Val::Px(8.0)),
+                margin: UiRect::px(10.0, 10.0, 10.0, 10.0),
+                ..default()
+            },
+            color: BackgroundColor(Color::srgba(0.09, 0.09, 0.1, 0.65)),
+            text: Text::new(score.0.to_string()),
+            ..default()
+        });
     });
 
-    cmd.spawn(GuiTopBundle::default());
+    cmd.spawn(GuiTopBundle::default()).with_children(|parent| {
+        parent
+            .spawn(SpellCardBannerBundle::default())
+            .with_children(SpellCardBannerBundle::spawn_children);
+    });
     cmd.spawn(GuiBottomBundle::default());
 
     cmd.spawn(GuiRightBundle::default())
@@ -48,6 +88,45 @@ fn setup_gui(mut cmd: Commands) {
                 text: Text::new(PLAYER_STAMINA.to_string()),
                 ..default()
             });
+            parent.spawn(HiScoreBundle {
+                node: Node {
+                    height: Val::Px(23.0),
+                    align_self: AlignSelf::Start,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    margin: UiRect::px(10.0, 10.0, 10.0, 10.0),
+                    ..default()
+                },
+                color: BackgroundColor(Color::srgba(0.09, 0.09, 0.1, 0.65)),
+                text: Text::new(hi_score.0.to_string()),
+                ..default()
+            });
+            parent.spawn(BombBarBundle {
+                node: Node {
+                    width: Val::Px(27.0),
+                    height: Val::Px(23.0),
+                    align_self: AlignSelf::End,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    margin: UiRect::px(0.0, 10.0, 10.0, 10.0),
+                    ..default()
+                },
+                color: BackgroundColor(Color::srgba(0.1, 0.35, 0.2, 0.65)),
+                text: Text::new(PLAYER_BOMBS.to_string()),
+                ..default()
+            });
+            parent.spawn(GrazeBarBundle {
+                node: Node {
+                    height: Val::Px(23.0),
+                    align_items: AlignItems::Center,
+                    align_self: AlignSelf::End,
+                    justify_self: JustifySelf::Center,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    margin: UiRect::px(0.0, 10.0, 10.0, 10.0),
+                    ..default()
+                },
+                color: BackgroundColor(Color::srgba(0.09, 0.09, 0.1, 0.65)),
+                text: Text::new("0"),
+                ..default()
+            });
         });
 }
 
@@ -96,9 +175,12 @@ fn toggle_gui_debug(
 impl Plugin for GuiPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(UiScale(3.))
-            .add_systems(OnEnter(AppState::Playing), setup_gui)
+            // Playing is entered again after a continue, the GUI is only built once
+            .add_systems(OnExit(AppState::LoadingLevelAssets), setup_gui)
             .add_plugins(GuiPartsPlugin)
-            .add_plugins(StatusBarPlugin);
+            .add_plugins(StatusBarPlugin)
+            .add_plugins(SpellCardGuiPlugin)
+            .add_plugins(ScoreGuiPlugin);
 
         #[cfg(debug_assertions)]
         app.insert_resource(GuiDebugLevel::default())
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::score::{HiScore, Score};
+
+#[derive(Component, Default)]
+pub struct ScoreText;
+
+#[derive(Component, Default)]
+pub struct HiScoreText;
+
+#[derive(Bundle, Default)]
+pub struct ScoreBundle {
+    pub entity: ScoreText,
+    pub color: BackgroundColor,
+    pub node: Node,
+    pub text: Text,
+}
+
+#[derive(Bundle, Default)]
+pub struct HiScoreBundle {
+    pub entity: HiScoreText,
+    pub color: BackgroundColor,
+    pub node: Node,
+    pub text: Text,
+}
+
+fn update(
+    mut scores: Query<&mut Text, (With<ScoreText>, Without<HiScoreText>)>,
+    mut hi_scores: Query<&mut Text, With<HiScoreText>>,
+    score: Res<Score>,
+    hi_score: Res<HiScore>,
+) {
+    if score.is_changed() {
+        for mut text in &mut scores {
+            **text = score.0.to_string();
+        }
+    }
+
+    if hi_score.is_changed() {
+        for mut text in &mut hi_scores {
+            **text = hi_score.0.to_string();
+        }
+    }
+}
+
+pub struct ScoreGuiPlugin;
+
+impl Plugin for ScoreGuiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update);
+    }
+}
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+
+use crate::spell_card::{CaptureBonus, SpellCardDeclared, SpellCardTimer};
+
+const BANNER_FONT_SIZE: f32 = 7.;
+const BONUS_COLOR: Color = Color::srgb(0.95, 0.85, 0.4);
+const BONUS_FAILED_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+
+#[derive(Component, Default)]
+pub struct SpellCardBanner;
+
+#[derive(Component, Default)]
+pub struct SpellCardTitle;
+
+#[derive(Component, Default)]
+pub struct SpellCardCountdown;
+
+#[derive(Component, Default)]
+pub struct SpellCardBonus;
+
+#[derive(Bundle)]
+pub struct SpellCardBannerBundle {
+    pub entity: SpellCardBanner,
+    pub node: Node,
+    pub color: BackgroundColor,
+    pub visibility: Visibility,
+}
+
+impl Default for SpellCardBannerBundle {
+    fn default() -> Self {
+        SpellCardBannerBundle {
+            entity: SpellCardBanner,
+            node: Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                align_self: AlignSelf::Start,
+                padding: UiRect::all(Val::Px(4.0)),
+                margin: UiRect::px(10.0, 10.0, 10.0, 10.0),
+                ..default()
+            },
+            color: BackgroundColor(Color::srgba(0.09, 0.09, 0.1, 0.65)),
+            visibility: Visibility::Hidden,
+        }
+    }
+}
+
+impl SpellCardBannerBundle {
+    pub fn spawn_children(parent: &mut ChildSpawnerCommands) {
+        let font = TextFont {
+            font_size: BANNER_FONT_SIZE,
+            ..default()
+        };
+        parent.spawn((SpellCardTitle, Text::default(), font.clone()));
+        parent.spawn((SpellCardCountdown, Text::default(), font.clone()));
+        parent.spawn((SpellCardBonus, Text::default(), font));
+    }
+}
+
+// Card shown in the banner
+#[derive(Resource, Default)]
+struct DeclaredSpellCard(Option<Entity>);
+
+fn declare(
+    mut declared_events: EventReader<SpellCardDeclared>,
+    mut declared: ResMut<DeclaredSpellCard>,
+    mut titles: Query<&mut Text, With<SpellCardTitle>>,
+) {
+    let Some(SpellCardDeclared { card, title }) = declared_events.read().last() else {
+        return;
+    };
+
+    declared.0 = Some(*card);
+    for mut text in &mut titles {
+        **text = title.to_string();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update(
+    declared: Res<DeclaredSpellCard>,
+    cards: Query<(&SpellCardTimer, &CaptureBonus)>,
+    mut banners: Query<&mut Visibility, With<SpellCardBanner>>,
+    mut countdowns: Query<&mut Text, (With<SpellCardCountdown>, Without<SpellCardBonus>)>,
+    mut bonuses: Query<(&mut Text, &mut TextColor), With<SpellCardBonus>>,
+) {
+    let card = declared.0.and_then(|card| cards.get(card).ok());
+
+    for mut visibility in &mut banners {
+        visibility.set_if_neq(match card {
+            Some(_) => Visibility::Inherited,
+            None => Visibility::Hidden,
+        });
+    }
+
+    let Some((timer, CaptureBonus(bonus))) = card else {
+        return;
+    };
+
+    for mut text in &mut countdowns {
+        **text = format!("{:.1}", timer.remaining().as_secs_f32());
+    }
+
+    for (mut text, mut color) in &mut bonuses {
+        (**text, color.0) = match bonus {
+            true => ("Bonus".to_string(), BONUS_COLOR),
+            false => ("Bonus Failed".to_string(), BONUS_FAILED_COLOR),
+        };
+    }
+}
+
+pub struct SpellCardGuiPlugin;
+
+impl Plugin for SpellCardGuiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DeclaredSpellCard>()
+            .add_systems(Update, (declare, update).chain());
+    }
+}
@@ -15,6 +15,9 @@ pub enum Action {
     Jump,
     Attack,
     SpecialAttack,
+    Focus,
+    Interact,
+    ToggleHitbox,
     #[actionlike(DualAxis)]
     Direction,
 }
@@ -29,6 +32,10 @@ fn setup_player_input_map(input_map: Single<&mut InputMap<Action>, Added<Player>
         (A::Jump, K::Space),
         (A::Dash, K::ShiftLeft),
         (A::Dash, K::ShiftRight),
+        (A::Focus, K::ControlLeft),
+        (A::Focus, K::ControlRight),
+        (A::Interact, K::KeyE),
+        (A::ToggleHitbox, K::KeyH),
     ]);
     input_map.insert_multiple([
         (A::Attack, MouseButton::Left),
@@ -57,6 +64,9 @@ fn setup_player_input_map(input_map: Single<&mut InputMap<Action>, Added<Player>
         (A::Dash, GB::RightTrigger),
         (A::Attack, GB::LeftTrigger),
         (A::SpecialAttack, GB::RightTrigger2),
+        (A::Focus, GB::LeftTrigger2),
+        (A::Interact, GB::North),
+        (A::ToggleHitbox, GB::Select),
     ]);
     input_map.insert_dual_axis(
         A::Direction,
@@ -81,6 +91,9 @@ impl Plugin for CustomInputPlugin {
 
         #[cfg(debug_assertions)]
         app.add_plugins(InputManagerPlugin::<DebugAction>::default())
-            .add_systems(OnEnter(AppState::Playing), debug::setup_debug_input_map);
+            .add_systems(
+                OnExit(AppState::LoadingLevelAssets),
+                debug::setup_debug_input_map,
+            );
     }
 }
@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+
+use super::config::*;
+use super::{DropItems, Item};
+use crate::enemy::health::{Damage, Hurtbox};
+use crate::player::interact::{Interact, Interactable};
+
+#[derive(Component, Default)]
+pub struct Chest {
+    content: Vec<Item>,
+    opened: bool,
+}
+
+impl Chest {
+    pub fn from_field(entity_instance: &EntityInstance) -> Self {
+        Chest {
+            content: Item::from_field(entity_instance, "content"),
+            opened: false,
+        }
+    }
+}
+
+// Chests opened so far, so they stay empty when their level respawns
+#[derive(Resource, Default, Debug)]
+pub struct OpenedChests(pub HashSet<EntityIid>);
+
+fn sprite(entity_instance: &EntityInstance) -> Sprite {
+    Sprite::from_color(
+        CHEST_COLOR,
+        Vec2::new(entity_instance.width as f32, entity_instance.height as f32),
+    )
+}
+
+#[derive(Bundle, Default, LdtkEntity)]
+pub struct ChestBundle {
+    #[with(Chest::from_field)]
+    pub chest: Chest,
+    #[with(sprite)]
+    pub sprite: Sprite,
+    // Lets attacks open the chest
+    #[from_entity_instance]
+    pub hurtbox: Hurtbox,
+    pub interactable: Interactable,
+    #[from_entity_instance]
+    pub entity_instance: EntityInstance,
+}
+
+#[derive(Event, Debug)]
+pub struct OpenChest(pub Entity);
+
+fn restore_chests(
+    mut cmd: Commands,
+    mut chests: Query<(Entity, &EntityIid, &mut Chest, &mut Sprite), Added<Chest>>,
+    opened: Res<OpenedChests>,
+) {
+    for (entity, iid, mut chest, mut sprite) in &mut chests {
+        if opened.0.contains(iid) {
+            chest.opened = true;
+            chest.content.clear();
+            sprite.color = CHEST_OPENED_COLOR;
+            cmd.entity(entity).remove::<(Hurtbox, Interactable)>();
+        }
+    }
+}
+
+fn open_by_attack(
+    mut damage_events: EventReader<Damage>,
+    chests: Query<(), With<Chest>>,
+    mut open_event: EventWriter<OpenChest>,
+) {
+    for Damage { target, .. } in damage_events.read() {
+        if chests.contains(*target) {
+            open_event.write(OpenChest(*target));
+        }
+    }
+}
+
+fn open_by_interact(
+    mut interact_events: EventReader<Interact>,
+    chests: Query<(), With<Chest>>,
+    mut open_event: EventWriter<OpenChest>,
+) {
+    for Interact(target) in interact_events.read() {
+        if chests.contains(*target) {
+            open_event.write(OpenChest(*target));
+        }
+    }
+}
+
+fn open_chest(
+    mut cmd: Commands,
+    mut open_events: EventReader<OpenChest>,
+    mut chests: Query<(&EntityIid, &mut Chest, &mut Sprite, &GlobalTransform)>,
+    mut opened: ResMut<OpenedChests>,
+    mut drop_event: EventWriter<DropItems>,
+) {
+    for OpenChest(entity) in open_events.read() {
+        let Ok((iid, mut chest, mut sprite, transform)) = chests.get_mut(*entity) else {
+            continue;
+        };
+        if chest.opened {
+            continue;
+        }
+
+        chest.opened = true;
+        opened.0.insert(iid.clone());
+        sprite.color = CHEST_OPENED_COLOR;
+        // Nothing left to hit or interact with
+        cmd.entity(*entity).remove::<(Hurtbox, Interactable)>();
+        drop_event.write(DropItems {
+            items: std::mem::take(&mut chest.content),
+            position: transform.translation().truncate(),
+        });
+    }
+}
+
+pub struct ChestPlugin;
+
+impl Plugin for ChestPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_ldtk_entity::<ChestBundle>("Chest")
+            .add_event::<OpenChest>()
+            .init_resource::<OpenedChests>()
+            .add_systems(
+                Update,
+                (
+                    restore_chests,
+                    (open_by_attack, open_by_interact),
+                    open_chest,
+                )
+                    .chain(),
+            );
+    }
+}
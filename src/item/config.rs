@@ -0,0 +1,10 @@
+use bevy::color::Color;
+
+pub const ITEM_SIZE: f32 = 8.;
+// Dropped items can't be picked up right away, so they're seen first
+pub const ITEM_PICKUP_DELAY: f32 = 0.5;
+pub const ITEM_PICKUP_RADIUS: f32 = 16.;
+pub const ITEM_DROP_SPREAD: f32 = 10.;
+
+pub const CHEST_COLOR: Color = Color::srgb(0., 0.6, 0.86);
+pub const CHEST_OPENED_COLOR: Color = Color::srgb(0.2, 0.3, 0.4);
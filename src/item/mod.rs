@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+use rand::prelude::*;
+use thiserror::Error;
+
+use crate::enemy::health::EnemyKilled;
+use crate::player::Player;
+
+pub mod chest;
+use chest::ChestPlugin;
+mod config;
+use config::*;
+
+#[derive(Debug, Error)]
+#[error("the given Item doesn't exist")]
+pub struct NoSuchItem;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum Item {
+    Knife,
+    HealingPlant,
+    Meat,
+    Boots,
+    Water,
+    Gem,
+    Key,
+}
+
+impl FromStr for Item {
+    type Err = NoSuchItem;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        use Item::*;
+        match string {
+            "Knife" => Ok(Knife),
+            "Healing_Plant" => Ok(HealingPlant),
+            "Meat" => Ok(Meat),
+            "Boots" => Ok(Boots),
+            "Water" => Ok(Water),
+            "Gem" => Ok(Gem),
+            "Key" => Ok(Key),
+            _ => Err(NoSuchItem),
+        }
+    }
+}
+
+impl Item {
+    // Same colors as the LDtk enum
+    pub fn color(&self) -> Color {
+        use Item::*;
+        match self {
+            Knife => Color::srgb_u8(0x92, 0x9D, 0xB5),
+            HealingPlant => Color::srgb_u8(0x46, 0x96, 0x35),
+            Meat => Color::srgb_u8(0xB7, 0x59, 0x50),
+            Boots => Color::srgb_u8(0x8A, 0x5C, 0x49),
+            Water => Color::srgb_u8(0x8F, 0x6C, 0x69),
+            Gem => Color::srgb_u8(0xAA, 0x7F, 0x38),
+            Key => Color::srgb_u8(0xFF, 0xD7, 0x00),
+        }
+    }
+
+    // Reads an Array<LocalEnum.Item> field
+    pub fn from_field(entity_instance: &EntityInstance, field: &str) -> Vec<Self> {
+        entity_instance
+            .get_maybe_enums_field(field)
+            .unwrap_or_else(|_| panic!("{} should have {field} field", entity_instance.identifier))
+            .iter()
+            .flatten()
+            .map(|item| Item::from_str(item).expect("Item with specified name should exist"))
+            .collect()
+    }
+}
+
+#[derive(Component, Debug, Default)]
+pub struct Inventory(HashMap<Item, u32>);
+
+impl Inventory {
+    pub fn add(&mut self, item: Item) {
+        *self.0.entry(item).or_default() += 1;
+    }
+
+    // Returns whether the item was there to take
+    pub fn take(&mut self, item: Item) -> bool {
+        match self.0.get_mut(&item) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn count(&self, item: Item) -> u32 {
+        self.0.get(&item).copied().unwrap_or_default()
+    }
+
+    pub fn from_field(entity_instance: &EntityInstance) -> Self {
+        let mut inventory = Inventory::default();
+        for item in Item::from_field(entity_instance, "items") {
+            inventory.add(item);
+        }
+        inventory
+    }
+}
+
+// Items dropped on death
+#[derive(Component, Debug, Default)]
+pub struct Loot(pub Vec<Item>);
+
+impl Loot {
+    pub fn from_field(entity_instance: &EntityInstance) -> Self {
+        Loot(Item::from_field(entity_instance, "loot"))
+    }
+}
+
+#[derive(Event, Debug)]
+pub struct DropItems {
+    pub items: Vec<Item>,
+    pub position: Vec2,
+}
+
+#[derive(Event, Debug)]
+pub struct ItemPickedUp(pub Item);
+
+#[derive(Component)]
+pub struct ItemPickup {
+    pub item: Item,
+    delay: Timer,
+}
+
+fn drop_items(mut cmd: Commands, mut drop_events: EventReader<DropItems>) {
+    let mut rng = rand::rng();
+
+    for DropItems { items, position } in drop_events.read() {
+        for item in items {
+            let offset = rng.random_range(-ITEM_DROP_SPREAD..=ITEM_DROP_SPREAD);
+            cmd.spawn((
+                Name::new(format!("{item:?}")),
+                ItemPickup {
+                    item: *item,
+                    delay: Timer::from_seconds(ITEM_PICKUP_DELAY, TimerMode::Once),
+                },
+                Sprite::from_color(item.color(), Vec2::splat(ITEM_SIZE)),
+                Transform::from_translation((position + Vec2::X * offset).extend(1.)),
+            ));
+        }
+    }
+}
+
+fn drop_loot(
+    mut killed_events: EventReader<EnemyKilled>,
+    enemies: Query<&Loot>,
+    mut drop_event: EventWriter<DropItems>,
+) {
+    for EnemyKilled { enemy, position } in killed_events.read() {
+        let Ok(Loot(items)) = enemies.get(*enemy) else {
+            continue;
+        };
+        if items.is_empty() {
+            continue;
+        }
+
+        drop_event.write(DropItems {
+            items: items.clone(),
+            position: *position,
+        });
+    }
+}
+
+fn pick_up(
+    mut cmd: Commands,
+    mut pickups: Query<(Entity, &mut ItemPickup, &GlobalTransform)>,
+    player: Single<(&GlobalTransform, &mut Inventory), With<Player>>,
+    mut picked_event: EventWriter<ItemPickedUp>,
+    time: Res<Time>,
+) {
+    let (player_transform, mut inventory) = player.into_inner();
+    let player_position = player_transform.translation().truncate();
+
+    for (pickup, mut item, transform) in &mut pickups {
+        if !item.delay.tick(time.delta()).finished() {
+            continue;
+        }
+
+        if transform.translation().truncate().distance(player_position) > ITEM_PICKUP_RADIUS {
+            continue;
+        }
+
+        inventory.add(item.item);
+        info!(
+            "Picked up {:?}, {} total",
+            item.item,
+            inventory.count(item.item)
+        );
+        picked_event.write(ItemPickedUp(item.item));
+        cmd.entity(pickup).despawn();
+    }
+}
+
+pub struct ItemPlugin;
+
+impl Plugin for ItemPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DropItems>()
+            .add_event::<ItemPickedUp>()
+            .add_plugins(ChestPlugin)
+            .add_systems(Update, ((drop_loot, drop_items).chain(), pick_up));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inventory_take() {
+        let mut inventory = Inventory::default();
+        assert!(!inventory.take(Item::Key));
+
+        inventory.add(Item::Key);
+        inventory.add(Item::Key);
+        inventory.add(Item::Gem);
+        assert_eq!(inventory.count(Item::Key), 2);
+
+        assert!(inventory.take(Item::Key));
+        assert!(inventory.take(Item::Key));
+        assert!(!inventory.take(Item::Key));
+        assert_eq!(inventory.count(Item::Key), 0);
+        assert_eq!(inventory.count(Item::Gem), 1);
+    }
+}
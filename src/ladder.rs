@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::walls::{level_grid, merge_tiles, tiles_by_level};
+
+#[derive(Eq, PartialEq, Debug, Default, Component)]
+pub struct Ladder;
+
+#[derive(Debug, Default, Bundle, LdtkIntCell)]
+pub struct LadderBundle {
+    ladder: Ladder,
+}
+
+// Merged area of ladder tiles the player can climb in
+#[derive(Debug, Default, Component)]
+pub struct LadderSensor;
+
+/// Spawns sensors over the ladders of a level, merged the same way as walls
+pub fn spawn_ladder_sensors(
+    mut commands: Commands,
+    ladder_query: Query<(&GridCoords, &ChildOf), Added<Ladder>>,
+    parent_query: Query<&ChildOf, Without<Ladder>>,
+    level_query: Query<(Entity, &LevelIid)>,
+    ldtk_projects: Query<&LdtkProjectHandle>,
+    ldtk_project_assets: Res<Assets<LdtkProject>>,
+) {
+    if ladder_query.is_empty() {
+        return;
+    }
+
+    let level_to_ladder_locations = tiles_by_level(ladder_query.iter(), &parent_query);
+
+    for (level_entity, level_iid) in &level_query {
+        let Some(level_ladders) = level_to_ladder_locations.get(&level_entity) else {
+            continue;
+        };
+
+        let (width, height, grid_size) =
+            level_grid(level_iid, &ldtk_projects, &ldtk_project_assets);
+
+        commands.entity(level_entity).with_children(|level| {
+            for ladder_rect in merge_tiles(level_ladders, width, height) {
+                let half_extents = ladder_rect.half_extents(grid_size);
+                level.spawn((
+                    LadderSensor,
+                    Collider::cuboid(half_extents.x, half_extents.y),
+                    Sensor,
+                    RigidBody::Fixed,
+                    Transform::from_translation(ladder_rect.translation(grid_size)),
+                ));
+            }
+        });
+    }
+}
+
+/// Ladders are int cell value 2
+pub struct LadderPlugin;
+
+impl Plugin for LadderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, spawn_ladder_sensors)
+            .register_ldtk_int_cell::<LadderBundle>(2);
+    }
+}
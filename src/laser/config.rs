@@ -0,0 +1,5 @@
+use bevy::color::Color;
+
+pub const LASER_COLOR: Color = Color::srgb(0.95, 0.35, 0.55);
+pub const LASER_WARNING_ALPHA: f32 = 0.35;
+pub const LASER_WARNING_WIDTH_SCALE: f32 = 0.15;
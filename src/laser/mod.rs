@@ -0,0 +1,257 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, sprite::Anchor};
+use serde::Deserialize;
+
+use crate::{
+    player::{hitbox::PlayerHitbox, stats::ChangeHealth, Player},
+    shared::deserialize_secs,
+};
+
+pub mod config;
+use config::*;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LaserParams {
+    pub length: f32,
+    pub width: f32,
+    // Telegraph, the laser is visible but harmless
+    #[serde(deserialize_with = "deserialize_secs")]
+    pub warning: Duration,
+    // Time it takes the laser to reach its full length once it's active
+    #[serde(deserialize_with = "deserialize_secs")]
+    pub growth: Duration,
+    #[serde(deserialize_with = "deserialize_secs")]
+    pub active: Duration,
+    // Radians per second
+    #[serde(default)]
+    pub rotation_speed: f32,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum LaserPhase {
+    #[default]
+    Warning,
+    Active,
+}
+
+#[derive(Component)]
+pub struct Laser {
+    pub params: LaserParams,
+    pub phase: LaserPhase,
+    timer: Timer,
+    // Lasers only hurt once, however long the player stays in them
+    hit: bool,
+}
+
+impl Laser {
+    pub fn new(params: LaserParams) -> Self {
+        Laser {
+            timer: Timer::new(params.warning, TimerMode::Once),
+            phase: LaserPhase::Warning,
+            hit: false,
+            params,
+        }
+    }
+
+    fn width(&self) -> f32 {
+        match self.phase {
+            LaserPhase::Warning => self.params.width * LASER_WARNING_WIDTH_SCALE,
+            LaserPhase::Active => self.params.width,
+        }
+    }
+
+    fn length(&self) -> f32 {
+        match self.phase {
+            LaserPhase::Warning => self.params.length,
+            LaserPhase::Active => {
+                let growth = self.params.growth.as_secs_f32();
+                let progress = match growth > 0. {
+                    true => (self.timer.elapsed_secs() / growth).min(1.),
+                    false => 1.,
+                };
+                self.params.length * progress
+            }
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct LaserBundle {
+    pub name: Name,
+    pub laser: Laser,
+    pub sprite: Sprite,
+    pub transform: Transform,
+}
+
+impl LaserBundle {
+    pub fn new(params: LaserParams, angle: f32) -> Self {
+        LaserBundle {
+            name: Name::new("Laser"),
+            sprite: Sprite {
+                color: LASER_COLOR.with_alpha(LASER_WARNING_ALPHA),
+                custom_size: Some(Vec2::new(
+                    params.length,
+                    params.width * LASER_WARNING_WIDTH_SCALE,
+                )),
+                anchor: Anchor::CenterLeft,
+                ..default()
+            },
+            transform: Transform::from_rotation(Quat::from_rotation_z(angle)),
+            laser: Laser::new(params),
+        }
+    }
+}
+
+fn laser_phase(
+    mut lasers: Query<(Entity, &mut Laser, &mut Sprite)>,
+    time: Res<Time>,
+    mut cmd: Commands,
+) {
+    for (entity, mut laser, mut sprite) in &mut lasers {
+        if !laser.timer.tick(time.delta()).finished() {
+            continue;
+        }
+
+        match laser.phase {
+            LaserPhase::Warning => {
+                laser.phase = LaserPhase::Active;
+                laser.timer = Timer::new(laser.params.active, TimerMode::Once);
+                sprite.color = LASER_COLOR;
+            }
+            LaserPhase::Active => cmd.entity(entity).despawn(),
+        }
+    }
+}
+
+fn laser_shape(mut lasers: Query<(&Laser, &mut Sprite, &mut Transform)>, time: Res<Time>) {
+    let delta = time.delta_secs();
+
+    for (laser, mut sprite, mut transform) in &mut lasers {
+        transform.rotate_z(laser.params.rotation_speed * delta);
+        sprite.custom_size = Some(Vec2::new(laser.length(), laser.width()));
+    }
+}
+
+fn segment_intersects_rect(start: Vec2, end: Vec2, rect: Rect) -> bool {
+    let direction = end - start;
+    let (mut enter, mut exit) = (0f32, 1f32);
+
+    for axis in 0..2 {
+        if direction[axis] == 0. {
+            if start[axis] < rect.min[axis] || start[axis] > rect.max[axis] {
+                return false;
+            }
+            continue;
+        }
+        let a = (rect.min[axis] - start[axis]) / direction[axis];
+        let b = (rect.max[axis] - start[axis]) / direction[axis];
+        enter = enter.max(a.min(b));
+        exit = exit.min(a.max(b));
+    }
+
+    enter <= exit
+}
+
+// Closest a point of the segment gets to the rect, zero if they cross
+fn segment_rect_distance(start: Vec2, end: Vec2, rect: Rect) -> f32 {
+    if segment_intersects_rect(start, end, rect) {
+        return 0.;
+    }
+
+    // Apart from crossing, the closest points are always at a segment end or a rect corner
+    let to_rect = |point: Vec2| point.distance(point.clamp(rect.min, rect.max));
+    let to_segment = |point: Vec2| {
+        let direction = end - start;
+        let t = match direction.length_squared() > 0. {
+            true => ((point - start).dot(direction) / direction.length_squared()).clamp(0., 1.),
+            false => 0.,
+        };
+        point.distance(start + direction * t)
+    };
+
+    let corners = [
+        rect.min,
+        rect.max,
+        Vec2::new(rect.min.x, rect.max.y),
+        Vec2::new(rect.max.x, rect.min.y),
+    ];
+    corners
+        .into_iter()
+        .map(to_segment)
+        .chain([to_rect(start), to_rect(end)])
+        .fold(f32::MAX, f32::min)
+}
+
+// The beam is a capsule around its center line, checked against the same hitbox as bullets
+fn laser_player_collision(
+    player: Single<(&GlobalTransform, &PlayerHitbox), With<Player>>,
+    mut lasers: Query<(&GlobalTransform, &mut Laser)>,
+    mut health_event: EventWriter<ChangeHealth>,
+) {
+    let (player_transform, hitbox) = *player;
+    let rect = hitbox.rect(player_transform.translation().truncate());
+    let mut hit = false;
+
+    for (transform, mut laser) in &mut lasers {
+        if laser.phase != LaserPhase::Active || laser.hit {
+            continue;
+        }
+
+        let start = transform.translation().truncate();
+        let end = transform
+            .transform_point(Vec3::X * laser.length())
+            .truncate();
+        if segment_rect_distance(start, end, rect) <= laser.width() / 2. {
+            laser.hit = true;
+            hit = true;
+        }
+    }
+
+    if hit {
+        health_event.write(ChangeHealth(-1));
+    }
+}
+
+pub struct LaserPlugin;
+
+impl Plugin for LaserPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (laser_phase, laser_shape, laser_player_collision).chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HITBOX: Rect = Rect {
+        min: Vec2::new(-1., -1.),
+        max: Vec2::new(1., 1.),
+    };
+
+    #[test]
+    fn laser_crossing_hitbox() {
+        let distance = segment_rect_distance(Vec2::new(-10., 0.5), Vec2::new(10., -0.5), HITBOX);
+        assert_eq!(distance, 0.);
+    }
+
+    #[test]
+    fn laser_beside_hitbox() {
+        let distance = segment_rect_distance(Vec2::new(-10., 3.), Vec2::new(10., 3.), HITBOX);
+        assert_eq!(distance, 2.);
+    }
+
+    #[test]
+    fn laser_rounded_end() {
+        // Past the end of the beam the distance is measured from its tip
+        let distance = segment_rect_distance(Vec2::new(-10., 5.), Vec2::new(-4., 5.), HITBOX);
+        assert_eq!(distance, 5.);
+
+        let distance = segment_rect_distance(Vec2::new(-10., 0.), Vec2::new(-3., 0.), HITBOX);
+        assert_eq!(distance, 2.);
+    }
+}
@@ -13,13 +13,19 @@ mod bullet_pattern;
 mod camera;
 mod colliders;
 mod config;
+mod door;
 mod enemy;
 mod ground_detection;
 mod gui;
 mod input;
+mod item;
+mod ladder;
+mod laser;
 mod main_menu;
 mod physics;
 mod player;
+mod save;
+mod score;
 mod shared;
 mod spell_card;
 mod state;
@@ -69,13 +75,19 @@ fn main() {
         .add_plugins(state::StatePlugin)
         .add_plugins(world::WorldPlugin)
         .add_plugins(walls::WallPlugin)
+        .add_plugins(ladder::LadderPlugin)
         .add_plugins(ground_detection::GroundDetectionPlugin)
         .add_plugins(player::PlayerPlugin)
         .add_plugins(physics::PhysicsPlugin)
         .add_plugins(bullet::BulletPlugin)
         .add_plugins(bullet_pattern::PatternPlugin)
+        .add_plugins(laser::LaserPlugin)
         .add_plugins(spell_card::SpellCardPlugin)
         .add_plugins(enemy::EnemyPlugin)
+        .add_plugins(item::ItemPlugin)
+        .add_plugins(door::DoorPlugin)
+        .add_plugins(score::ScorePlugin)
+        .add_plugins(save::SavePlugin)
         .add_plugins(camera::CameraPlugin)
         .add_plugins(EguiPlugin::default())
         .add_plugins(WorldInspectorPlugin::new())
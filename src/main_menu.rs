@@ -1,14 +1,111 @@
+use crate::save::{PendingLoad, SaveData};
 use crate::state::AppState;
 use bevy::prelude::*;
 
+const MENU_FONT_SIZE: f32 = 24.;
+const BUTTON_COLOR: Color = Color::srgb(0.15, 0.15, 0.18);
+const BUTTON_HOVERED_COLOR: Color = Color::srgb(0.25, 0.25, 0.3);
+
 pub(super) struct MainMenuPlugin;
 
-fn main_menu(mut state: ResMut<NextState<AppState>>) {
-    state.set(AppState::LoadingLevel);
+#[derive(Component)]
+struct MainMenu;
+
+#[derive(Component, Debug, Clone, Copy)]
+enum MenuButton {
+    NewGame,
+    Continue,
+}
+
+// Shared with the other menus, colored by button_colors
+pub(crate) fn menu_button(label: &str) -> impl Bundle {
+    (
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(16.), Val::Px(8.)),
+            margin: UiRect::all(Val::Px(4.)),
+            ..default()
+        },
+        BackgroundColor(BUTTON_COLOR),
+        children![(
+            Text::new(label),
+            TextFont {
+                font_size: MENU_FONT_SIZE,
+                ..default()
+            },
+        )],
+    )
+}
+
+fn spawn_button(parent: &mut ChildSpawnerCommands, button: MenuButton, label: &str) {
+    parent.spawn((button, menu_button(label)));
+}
+
+fn main_menu(mut cmd: Commands) {
+    // Continue is only offered when there's something to load
+    let has_save = SaveData::exists();
+
+    cmd.spawn((
+        MainMenu,
+        Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+    ))
+    .with_children(|menu| {
+        if has_save {
+            spawn_button(menu, MenuButton::Continue, "Continue");
+        }
+        spawn_button(menu, MenuButton::NewGame, "New Game");
+    });
+}
+
+fn button_colors(
+    mut buttons: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<Button>)>,
+) {
+    for (interaction, mut color) in &mut buttons {
+        color.0 = match interaction {
+            Interaction::Hovered | Interaction::Pressed => BUTTON_HOVERED_COLOR,
+            Interaction::None => BUTTON_COLOR,
+        };
+    }
+}
+
+fn menu_buttons(
+    mut cmd: Commands,
+    buttons: Query<(&Interaction, &MenuButton), Changed<Interaction>>,
+    mut state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let MenuButton::Continue = button {
+            match SaveData::load() {
+                Some(save) => cmd.insert_resource(PendingLoad(save)),
+                None => continue,
+            }
+        }
+        state.set(AppState::LoadingLevel);
+    }
+}
+
+fn despawn_main_menu(mut cmd: Commands, menus: Query<Entity, With<MainMenu>>) {
+    for menu in &menus {
+        cmd.entity(menu).despawn();
+    }
 }
 
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, main_menu);
+        app.add_systems(OnEnter(AppState::MainMenu), main_menu)
+            .add_systems(Update, menu_buttons.run_if(in_state(AppState::MainMenu)))
+            .add_systems(Update, button_colors)
+            .add_systems(OnExit(AppState::MainMenu), despawn_main_menu);
     }
 }
@@ -1,10 +1,13 @@
 use bevy::prelude::*;
+use bevy::time::Stopwatch;
 use bevy_rapier2d::prelude::*;
+use serde::Deserialize;
 use std::f32::consts::PI;
 
 use super::Acceleration;
+use crate::player::Player;
 
-#[derive(Component, Default, PartialEq, Debug, Clone)]
+#[derive(Component, Default, PartialEq, Debug, Clone, Deserialize)]
 pub enum MovementType {
     #[default]
     Still,
@@ -16,8 +19,33 @@ pub enum MovementType {
         speed: f32,
         accel: f32,
     },
+    Homing {
+        speed: f32,
+        accel: f32,
+        max_speed: f32,
+        // Radians per second
+        turn_rate: f32,
+        // Seconds of homing before flying straight, forever if None
+        duration: Option<f32>,
+    },
+    Wave {
+        speed: f32,
+        // Bullets fly away from the pattern's center if zero
+        direction: Vec2,
+        amplitude: f32,
+        // Oscillations per second
+        frequency: f32,
+        // Radians
+        phase: f32,
+        // Added to the phase of every next bullet in the pattern
+        phase_step: f32,
+    },
 }
 
+/// Time since the entity started moving
+#[derive(Component, Default)]
+pub struct MovementTime(pub Stopwatch);
+
 impl MovementType {
     pub fn start_velocity(&self, position: Vec2) -> Vec2 {
         use MovementType::*;
@@ -28,6 +56,8 @@ impl MovementType {
                 let velocity = Vec2::from_angle(PI / 2.).rotate(position);
                 velocity / velocity.length() * speed
             }
+            Homing { speed, .. } => position.normalize_or_zero() * speed,
+            Wave { .. } => self.wave_velocity(0.).unwrap_or_default(),
         }
     }
     pub fn acceleration(&self, position: Vec2) -> Vec2 {
@@ -36,8 +66,74 @@ impl MovementType {
             Still => Vec2::ZERO,
             Linear { velocity, accel } => velocity.normalize_or_zero() * accel,
             Circle { speed: _, accel } => -position.normalize_or_zero() * accel,
+            Homing { .. } => Vec2::ZERO,
+            Wave { .. } => Vec2::ZERO,
         }
     }
+    // For movement types that can't be expressed with acceleration alone
+    pub fn steer(
+        &self,
+        velocity: Vec2,
+        to_target: Option<Vec2>,
+        elapsed: f32,
+        delta: f32,
+    ) -> Option<Vec2> {
+        use MovementType::*;
+        match self {
+            Homing {
+                accel,
+                max_speed,
+                turn_rate,
+                duration,
+                ..
+            } => {
+                let speed = (velocity.length() + accel * delta).min(*max_speed);
+                let homing = duration.is_none_or(|duration| elapsed < duration);
+
+                let direction = match to_target {
+                    Some(to_target) if homing => {
+                        turn_toward(velocity, to_target, turn_rate * delta)
+                    }
+                    _ => velocity.normalize_or_zero(),
+                };
+                Some(direction * speed)
+            }
+            Wave { .. } => self.wave_velocity(elapsed),
+            _ => None,
+        }
+    }
+
+    // Derivative of the offset `amplitude * sin(2π * frequency * t + phase)`
+    fn wave_velocity(&self, elapsed: f32) -> Option<Vec2> {
+        let MovementType::Wave {
+            speed,
+            direction,
+            amplitude,
+            frequency,
+            phase,
+            ..
+        } = self
+        else {
+            return None;
+        };
+
+        let direction = direction.normalize_or_zero();
+        let angular_frequency = 2. * PI * frequency;
+        let oscillation =
+            amplitude * angular_frequency * (angular_frequency * elapsed + phase).cos();
+
+        Some(direction * *speed + direction.perp() * oscillation)
+    }
+}
+
+fn turn_toward(direction: Vec2, target: Vec2, max_angle: f32) -> Vec2 {
+    let (direction, target) = (direction.normalize_or_zero(), target.normalize_or_zero());
+    if direction == Vec2::ZERO {
+        return target;
+    }
+
+    let angle = direction.perp_dot(target).atan2(direction.dot(target));
+    Vec2::from_angle(angle.clamp(-max_angle, max_angle)).rotate(direction)
 }
 
 fn movement_start_velocity(
@@ -49,12 +145,51 @@ fn movement_start_velocity(
     }
 }
 
+fn movement_time(
+    mut entities: Query<&mut MovementTime, Without<RigidBodyDisabled>>,
+    time: Res<Time>,
+) {
+    for mut movement_time in &mut entities {
+        movement_time.0.tick(time.delta());
+    }
+}
+
+#[allow(clippy::type_complexity)]
 fn movement_acceleration(
-    mut entities: Query<(&MovementType, &Transform, &mut Acceleration), Without<RigidBodyDisabled>>,
+    target: Option<Single<&GlobalTransform, With<Player>>>,
+    mut entities: Query<
+        (
+            &MovementType,
+            &Transform,
+            &GlobalTransform,
+            Option<&MovementTime>,
+            &mut Velocity,
+            &mut Acceleration,
+        ),
+        Without<RigidBodyDisabled>,
+    >,
+    time: Res<Time>,
 ) {
-    for (movement, transform, mut accel) in &mut entities {
+    let delta = time.delta_secs();
+    let target = target.map(|target| target.translation().truncate());
+
+    for (movement, transform, global_transform, movement_time, mut velocity, mut accel) in
+        &mut entities
+    {
         let relative_position = transform.translation.truncate();
         accel.0 = movement.acceleration(relative_position);
+
+        // Velocity is relative to the parent pattern, so the target has to be as well
+        let to_parent = global_transform.affine() * transform.compute_affine().inverse();
+        let to_target = target.map(|target| {
+            let target = to_parent.inverse().transform_point3(target.extend(0.));
+            target.truncate() - relative_position
+        });
+        let elapsed = movement_time.map_or(0., |movement_time| movement_time.0.elapsed_secs());
+
+        if let Some(linvel) = movement.steer(velocity.linvel, to_target, elapsed, delta) {
+            velocity.linvel = linvel;
+        }
     }
 }
 
@@ -63,6 +198,62 @@ pub struct MovementTypePlugin;
 impl Plugin for MovementTypePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Update, movement_start_velocity)
-            .add_systems(Update, movement_acceleration);
+            .add_systems(Update, (movement_time, movement_acceleration).chain());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOMING: MovementType = MovementType::Homing {
+        speed: 100.,
+        accel: 0.,
+        max_speed: 100.,
+        turn_rate: PI / 2.,
+        duration: Some(1.),
+    };
+
+    #[test]
+    fn homing_turn_rate() {
+        let delta = 0.1;
+        let max_angle = PI / 2. * delta;
+
+        let velocity = Vec2::X * 100.;
+        let steered = HOMING.steer(velocity, Some(Vec2::Y), 0., delta).unwrap();
+        assert!((velocity.angle_to(steered) - max_angle).abs() < 1e-5);
+        assert!((steered.length() - 100.).abs() < 1e-3);
+
+        // Targets within the turn rate are aimed at directly
+        let close = Vec2::from_angle(max_angle / 2.);
+        let steered = HOMING.steer(velocity, Some(close), 0., delta).unwrap();
+        assert!(steered.normalize().abs_diff_eq(close, 1e-5));
+    }
+
+    #[test]
+    fn wave_velocity() {
+        let wave = MovementType::Wave {
+            speed: 50.,
+            direction: Vec2::new(2., 0.),
+            amplitude: 10.,
+            frequency: 1.,
+            phase: 0.,
+            phase_step: 0.,
+        };
+
+        // Fastest sideways through the middle of the wave, not at all at its peak
+        let velocity = wave.wave_velocity(0.).unwrap();
+        assert!(velocity.abs_diff_eq(Vec2::new(50., 20. * PI), 1e-3));
+        let velocity = wave.wave_velocity(0.25).unwrap();
+        assert!(velocity.abs_diff_eq(Vec2::new(50., 0.), 1e-3));
+
+        assert_eq!(HOMING.wave_velocity(0.), None);
+    }
+
+    #[test]
+    fn homing_flies_straight_after_duration() {
+        let velocity = Vec2::new(30., 40.);
+        let steered = HOMING.steer(velocity, Some(Vec2::NEG_Y), 1.5, 0.1).unwrap();
+        assert!(steered.abs_diff_eq(velocity, 1e-5));
     }
 }
@@ -1,21 +1,35 @@
 use std::time::Duration;
 
 use crate::{
-    colliders::SensorBundle,
+    bullet::{collision::BulletGridUpdate, grid::BulletGrid, Bullet},
+    enemy::health::{Damage, Hurtbox, Knockback},
+    physics::DespawnIfOutOfBounds,
     player::{
+        attack::reflect::ReflectedBullet,
+        config::attack::*,
         state::{PlayerState, QueuePlayerState},
+        stats::ChangeStamina,
         LookingDirection, Player,
     },
 };
 use bevy::prelude::*;
 // use bevy_aseprite_ultra::prelude::{AnimationRepeat, AseAnimation};
-use bevy_rapier2d::prelude::Collider;
+use bevy_rapier2d::prelude::Velocity;
+
+// What happens to enemy bullets caught in the swing
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Parry {
+    #[default]
+    Destroy,
+    Reflect,
+}
 
 #[derive(Event)]
 pub struct MeleeEvent {
     area: Rectangle,
     offset: Vec2,
     duration: Duration,
+    parry: Parry,
 }
 
 impl MeleeEvent {
@@ -24,12 +38,37 @@ impl MeleeEvent {
             area,
             offset,
             duration,
+            parry: Parry::default(),
         }
     }
+
+    pub fn with_parry(mut self, parry: Parry) -> Self {
+        self.parry = parry;
+        self
+    }
 }
 
 #[derive(Component)]
-struct Melee;
+struct Melee {
+    area: Rectangle,
+    parry: Parry,
+    // Every target is hit only once per attack
+    hits: Vec<Entity>,
+    // Stamina is paid once per attack, however many bullets it sends back
+    reflected: bool,
+}
+
+// Briefly slows the game down when a hit lands
+#[derive(Resource)]
+struct HitStop(Timer);
+
+impl Default for HitStop {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(PLAYER_HIT_STOP_TIME, TimerMode::Once);
+        timer.tick(timer.duration());
+        HitStop(timer)
+    }
+}
 
 #[derive(Component)]
 struct MeleeTimer(pub Timer);
@@ -46,22 +85,23 @@ fn spawn_attack(
         area,
         offset,
         duration,
+        parry,
     } in melee_events.read()
     {
         let attack = cmd
             .spawn((
-                Melee,
-                MeleeTimer(Timer::new(*duration, TimerMode::Once)),
-                SensorBundle {
-                    collider: Collider::cuboid(area.half_size.x, area.half_size.y),
-                    ..default()
+                Melee {
+                    area: *area,
+                    parry: *parry,
+                    hits: Vec::new(),
+                    reflected: false,
                 },
+                MeleeTimer(Timer::new(*duration, TimerMode::Once)),
                 Transform::from_xyz(offset.x * Into::<f32>::into(look_dir), offset.y, 10.),
             ))
             .id();
 
         cmd.entity(player).add_child(attack);
-        println!("spawn!");
         state_event.write(QueuePlayerState(PlayerState::Attack));
     }
 }
@@ -74,16 +114,125 @@ fn despawn_attack(
     for (attack, mut timer) in &mut attacks {
         if timer.0.tick(time.delta()).finished() {
             cmd.entity(attack).despawn();
-            println!("despawn!");
         }
     }
 }
 
+#[allow(clippy::type_complexity)]
+fn melee_hit(
+    mut cmd: Commands,
+    mut attacks: Query<(&GlobalTransform, &mut Melee)>,
+    targets: Query<(Entity, &GlobalTransform, &Hurtbox)>,
+    mut knockbacks: Query<&mut Velocity, Without<Player>>,
+    player: Single<(&mut Velocity, &LookingDirection), With<Player>>,
+    mut damage_event: EventWriter<Damage>,
+    mut hit_stop: ResMut<HitStop>,
+) {
+    let (mut player_velocity, LookingDirection(look_dir)) = player.into_inner();
+    let direction: f32 = look_dir.into();
+
+    for (transform, mut melee) in &mut attacks {
+        let area =
+            Rect::from_center_half_size(transform.translation().truncate(), melee.area.half_size);
+
+        for (target, target_transform, hurtbox) in &targets {
+            let hurtbox = hurtbox.rect(target_transform.translation().truncate());
+            if melee.hits.contains(&target) || area.intersect(hurtbox).is_empty() {
+                continue;
+            }
+            melee.hits.push(target);
+            damage_event.write(Damage {
+                target,
+                amount: PLAYER_MELEE_DAMAGE,
+            });
+
+            if let Ok(mut velocity) = knockbacks.get_mut(target) {
+                velocity.linvel.x += direction * PLAYER_MELEE_KNOCKBACK;
+                cmd.entity(target).try_insert(Knockback::default());
+            }
+            player_velocity.linvel.x -= direction * PLAYER_MELEE_RECOIL;
+            hit_stop.0.reset();
+        }
+    }
+}
+
+fn melee_parry(
+    mut cmd: Commands,
+    mut attacks: Query<(&GlobalTransform, &mut Melee)>,
+    bullets: Query<(&Sprite, &Velocity), With<Bullet>>,
+    player: Single<&GlobalTransform, With<Player>>,
+    grid: Res<BulletGrid>,
+    mut stamina_event: EventWriter<ChangeStamina>,
+) {
+    let center = player.translation().truncate();
+
+    for (transform, mut melee) in &mut attacks {
+        let area =
+            Rect::from_center_half_size(transform.translation().truncate(), melee.area.half_size);
+
+        for bullet in grid.query_rect(area) {
+            let Ok((sprite, velocity)) = bullets.get(bullet.entity) else {
+                continue;
+            };
+            cmd.entity(bullet.entity).try_despawn();
+
+            if melee.parry != Parry::Reflect {
+                continue;
+            }
+            if !melee.reflected {
+                melee.reflected = true;
+                stamina_event.write(ChangeStamina(-PLAYER_REFLECT_STAMINA_COST));
+            }
+
+            // Sent back the way it came, or away from the player for bullets standing still
+            let direction = (-velocity.linvel)
+                .try_normalize()
+                .unwrap_or((bullet.position - center).normalize_or(Vec2::X));
+
+            cmd.spawn((
+                ReflectedBullet {
+                    damage: PLAYER_REFLECT_DAMAGE,
+                },
+                Velocity::linear(direction * PLAYER_REFLECT_SPEED),
+                sprite.clone(),
+                Transform::from_translation(bullet.position.extend(transform.translation().z)),
+                DespawnIfOutOfBounds,
+            ));
+        }
+    }
+}
+
+fn hit_stop(
+    mut hit_stop: ResMut<HitStop>,
+    mut time: ResMut<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+) {
+    let stopped = !hit_stop.0.tick(real_time.delta()).finished();
+    let speed = match stopped {
+        true => PLAYER_HIT_STOP_SPEED,
+        false => 1.,
+    };
+
+    if time.relative_speed() != speed {
+        time.set_relative_speed(speed);
+    }
+}
+
 pub(super) struct MeleePlugin;
 
 impl Plugin for MeleePlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<MeleeEvent>()
-            .add_systems(Update, (spawn_attack, despawn_attack));
+            .init_resource::<HitStop>()
+            .add_systems(
+                Update,
+                (
+                    spawn_attack,
+                    (melee_hit, melee_parry).after(BulletGridUpdate),
+                    despawn_attack,
+                )
+                    .chain(),
+            )
+            .add_systems(Update, hit_stop);
     }
 }
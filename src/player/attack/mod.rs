@@ -3,7 +3,9 @@ use std::time::Duration;
 use crate::{
     input::Action,
     player::{
-        attack::melee::{MeleeEvent, MeleePlugin},
+        attack::melee::{MeleeEvent, MeleePlugin, Parry},
+        config::attack::*,
+        stats::PlayerStats,
         Player,
     },
 };
@@ -11,15 +13,30 @@ use bevy::prelude::*;
 use leafwing_input_manager::prelude::ActionState;
 
 mod melee;
+mod reflect;
+use reflect::ReflectPlugin;
+mod special_atack;
+use special_atack::SpecialAttackPlugin;
 
-fn attack(input: Single<&ActionState<Action>, With<Player>>, mut event: EventWriter<MeleeEvent>) {
-    let keys = input.into_inner();
+fn attack(
+    player: Single<(&ActionState<Action>, &PlayerStats), With<Player>>,
+    mut event: EventWriter<MeleeEvent>,
+) {
+    let (keys, stats) = player.into_inner();
     if keys.just_pressed(&Action::Attack) {
-        event.write(MeleeEvent::new(
-            Rectangle::new(1., 1.),
-            Vec2::new(1., 0.),
-            Duration::from_secs_f64(1.),
-        ));
+        // Reflecting costs stamina, an exhausted player can only swat bullets away
+        let parry = match stats.stamina > 0 {
+            true => Parry::Reflect,
+            false => Parry::Destroy,
+        };
+        event.write(
+            MeleeEvent::new(
+                Rectangle::new(PLAYER_MELEE_WIDTH, PLAYER_MELEE_HEIGHT),
+                Vec2::new(PLAYER_MELEE_OFFSET, 0.),
+                Duration::from_secs_f64(PLAYER_MELEE_TIME),
+            )
+            .with_parry(parry),
+        );
     }
 }
 
@@ -27,6 +44,9 @@ pub(super) struct AttackPlugin;
 
 impl Plugin for AttackPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(MeleePlugin).add_systems(Update, attack);
+        app.add_plugins(MeleePlugin)
+            .add_plugins(SpecialAttackPlugin)
+            .add_plugins(ReflectPlugin)
+            .add_systems(Update, attack);
     }
 }
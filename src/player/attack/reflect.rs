@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+use crate::{
+    enemy::health::{Damage, Hurtbox},
+    player::config::attack::PLAYER_REFLECT_RADIUS,
+};
+
+// Enemy bullet parried back, it only hits enemies
+#[derive(Component)]
+pub struct ReflectedBullet {
+    pub damage: i64,
+}
+
+fn reflected_movement(
+    mut bullets: Query<(&Velocity, &mut Transform), With<ReflectedBullet>>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_secs();
+    for (velocity, mut transform) in &mut bullets {
+        transform.translation += (velocity.linvel * delta).extend(0.);
+    }
+}
+
+fn reflected_hit(
+    mut cmd: Commands,
+    bullets: Query<(Entity, &GlobalTransform, &ReflectedBullet)>,
+    targets: Query<(Entity, &GlobalTransform, &Hurtbox)>,
+    mut damage_event: EventWriter<Damage>,
+) {
+    for (bullet, transform, ReflectedBullet { damage }) in &bullets {
+        let position = transform.translation().truncate();
+
+        let hit = targets.iter().find(|(_, target_transform, hurtbox)| {
+            let rect = hurtbox.rect(target_transform.translation().truncate());
+            position.distance(position.clamp(rect.min, rect.max)) <= PLAYER_REFLECT_RADIUS
+        });
+
+        if let Some((target, ..)) = hit {
+            damage_event.write(Damage {
+                target,
+                amount: *damage,
+            });
+            cmd.entity(bullet).despawn();
+        }
+    }
+}
+
+pub(super) struct ReflectPlugin;
+
+impl Plugin for ReflectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (reflected_movement, reflected_hit).chain());
+    }
+}
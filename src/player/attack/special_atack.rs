@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::ActionState;
+
+use crate::{
+    bullet::cancel::{CancelArea, CancelBullets},
+    enemy::health::{Damage, Hurtbox},
+    input::Action,
+    player::{
+        config::attack::*,
+        config::stats::PLAYER_HEALTH,
+        death::PlayerDying,
+        stats::{InvincibilityTimer, PlayerStats, RecentHit},
+        Player,
+    },
+};
+
+#[derive(Event)]
+pub struct Bomb;
+
+#[derive(Component)]
+pub struct Bombing(Timer);
+
+#[derive(Component)]
+struct BombEffect;
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn bomb(
+    mut cmd: Commands,
+    player: Single<
+        (Entity, &ActionState<Action>, &mut PlayerStats),
+        (With<Player>, Without<Bombing>, Without<PlayerDying>),
+    >,
+    mut recent_hit: ResMut<RecentHit>,
+    mut invincibility: ResMut<InvincibilityTimer>,
+    mut bomb_event: EventWriter<Bomb>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let (entity, input, mut stats) = player.into_inner();
+
+    if !input.just_pressed(&Action::SpecialAttack) || stats.bombs <= 0 {
+        return;
+    }
+
+    stats.bombs -= 1;
+
+    let cancelled = recent_hit.cancel();
+    if cancelled > 0 {
+        stats.health = (stats.health + cancelled).min(PLAYER_HEALTH);
+        info!("Deathbomb!");
+    }
+
+    invincibility.start(PLAYER_BOMB_TIME);
+    bomb_event.write(Bomb);
+
+    cmd.entity(entity)
+        .insert(Bombing(Timer::from_seconds(
+            PLAYER_BOMB_TIME,
+            TimerMode::Once,
+        )))
+        .with_child((
+            BombEffect,
+            Mesh2d(meshes.add(Circle::new(PLAYER_BOMB_RADIUS))),
+            MeshMaterial2d(materials.add(PLAYER_BOMB_COLOR)),
+            Transform::from_xyz(0., 0., PLAYER_BOMB_Z).with_scale(Vec3::ZERO),
+        ));
+}
+
+// Damages everything in range once, when the bomb goes off
+fn bomb_damage(
+    mut bomb_events: EventReader<Bomb>,
+    player: Single<&GlobalTransform, With<Player>>,
+    targets: Query<(Entity, &GlobalTransform), With<Hurtbox>>,
+    mut damage_event: EventWriter<Damage>,
+) {
+    if bomb_events.read().count() == 0 {
+        return;
+    }
+
+    let center = player.translation().truncate();
+    for (target, transform) in &targets {
+        if transform.translation().truncate().distance(center) <= PLAYER_BOMB_RADIUS {
+            damage_event.write(Damage {
+                target,
+                amount: PLAYER_BOMB_DAMAGE,
+            });
+        }
+    }
+}
+
+// Keeps clearing bullets around the player while the bomb lasts
+fn bombing(
+    mut cmd: Commands,
+    player: Single<(Entity, &GlobalTransform, &mut Bombing), With<Player>>,
+    mut effects: Query<(Entity, &mut Transform), With<BombEffect>>,
+    mut cancel_event: EventWriter<CancelBullets>,
+    time: Res<Time>,
+) {
+    let (entity, transform, mut bombing) = player.into_inner();
+    let first_frame = bombing.0.elapsed().is_zero();
+
+    cancel_event.write(CancelBullets {
+        area: CancelArea::Radius {
+            center: transform.translation().truncate(),
+            radius: PLAYER_BOMB_RADIUS,
+        },
+        items: first_frame,
+    });
+
+    let grown = (bombing.0.elapsed_secs() / PLAYER_BOMB_GROW_TIME).min(1.);
+    for (_, mut effect) in &mut effects {
+        effect.scale = Vec3::splat(grown);
+    }
+
+    if bombing.0.tick(time.delta()).finished() {
+        cmd.entity(entity).remove::<Bombing>();
+        for (effect, _) in &effects {
+            cmd.entity(effect).despawn();
+        }
+    }
+}
+
+pub(super) struct SpecialAttackPlugin;
+
+impl Plugin for SpecialAttackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Bomb>()
+            .add_systems(Update, (bomb, bomb_damage, bombing).chain());
+    }
+}
@@ -0,0 +1,24 @@
+use bevy::color::Color;
+
+pub const PLAYER_MELEE_WIDTH: f32 = 24.;
+pub const PLAYER_MELEE_HEIGHT: f32 = 28.;
+pub const PLAYER_MELEE_OFFSET: f32 = 18.;
+pub const PLAYER_MELEE_TIME: f64 = 0.3;
+pub const PLAYER_MELEE_DAMAGE: i64 = 1;
+
+pub const PLAYER_BOMB_RADIUS: f32 = 160.;
+pub const PLAYER_BOMB_TIME: f32 = 2.;
+pub const PLAYER_BOMB_GROW_TIME: f32 = 0.3;
+pub const PLAYER_BOMB_DAMAGE: i64 = 10;
+pub const PLAYER_BOMB_COLOR: Color = Color::srgba(0.9, 0.3, 0.4, 0.25);
+pub const PLAYER_BOMB_Z: f32 = 5.;
+
+pub const PLAYER_HIT_STOP_TIME: f32 = 0.06;
+pub const PLAYER_HIT_STOP_SPEED: f32 = 0.05;
+pub const PLAYER_MELEE_KNOCKBACK: f32 = 180.;
+pub const PLAYER_MELEE_RECOIL: f32 = 90.;
+
+pub const PLAYER_REFLECT_SPEED: f32 = 400.;
+pub const PLAYER_REFLECT_RADIUS: f32 = 4.;
+pub const PLAYER_REFLECT_DAMAGE: i64 = 1;
+pub const PLAYER_REFLECT_STAMINA_COST: i64 = 1;
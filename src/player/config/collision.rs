@@ -1,5 +1,13 @@
+use bevy::color::Color;
+
 pub const PLAYER_WIDTH: f32 = 12.;
 pub const PLAYER_HEIGHT: f32 = 36.;
 
 // pub const PLAYER_OFFSET_X: f32 = 0.;
 // pub const PLAYER_OFFSET_Y: f32 = -4.;
+
+pub const PLAYER_HITBOX_SIZE: f32 = 4.;
+pub const PLAYER_HITBOX_COLOR: Color = Color::srgb(1., 1., 1.);
+pub const PLAYER_HITBOX_Z: f32 = 10.;
+pub const PLAYER_GRAZE_RADIUS: f32 = 24.;
+pub const PLAYER_INTERACT_RADIUS: f32 = 24.;
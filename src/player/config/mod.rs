@@ -1,5 +1,6 @@
 use crate::physics::looking_direction::LookDir;
 
+pub mod attack;
 pub mod collision;
 pub mod physics;
 pub mod stats;
@@ -4,6 +4,9 @@ pub const PLAYER_JUMP_STRENGTH: f32 = 650.;
 // 1200.
 pub const PLAYER_DASH_STRENGTH: f32 = 700.;
 pub const PLAYER_AUTOSTEP_AMOUNT: f32 = 0.007;
+pub const PLAYER_FOCUS_SPEED_SCALE: f32 = 0.4;
+pub const PLAYER_CLIMB_SPEED: f32 = 80.;
+pub const PLAYER_CLIMB_SIDE_SPEED: f32 = 40.;
 
 // Use with deltatime
 pub const PLAYER_GRAVITY: f64 = 830.;
@@ -1,4 +1,14 @@
 pub const PLAYER_HEALTH: i64 = 10;
 pub const PLAYER_STAMINA: i64 = 3;
+// Extra lives, the game is over when dying without any left
+pub const PLAYER_LIVES: i64 = 2;
+pub const PLAYER_BOMBS: i64 = 3;
 pub const PLAYER_STAMINA_REGEN_TIME: f32 = 0.8;
 pub const PLAYER_INVINCIBILITY_TIME: f32 = 0.45;
+pub const PLAYER_RESPAWN_INVINCIBILITY_TIME: f32 = 2.5;
+pub const PLAYER_DEATH_TIME: f32 = 1.2;
+// Time after a hit during which bombing cancels it
+pub const PLAYER_DEATHBOMB_WINDOW: f32 = 0.15;
+pub const PLAYER_DEATH_BLINK_FREQUENCY: f32 = 12.;
+// Amount of grazes restoring one stamina point
+pub const PLAYER_GRAZES_PER_STAMINA: u64 = 10;
@@ -0,0 +1,215 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+use super::config::stats::*;
+use super::stats::{InvincibilityTimer, PlayerDied, PlayerStats, RecentHit};
+use super::{ControlsEnabled, PhysicsEnabled, Player};
+use crate::bullet::cancel::{CancelArea, CancelBullets};
+use crate::main_menu::menu_button;
+use crate::state::AppState;
+
+#[derive(Component)]
+pub struct PlayerDying(Timer);
+
+// Brings the player back at the respawn point, lives are handled by the sender
+#[derive(Event, Default)]
+pub struct PlayerRespawn;
+
+// Where the player comes back after dying, the level entrance until a checkpoint is reached
+#[derive(Resource, Default, Debug)]
+pub struct RespawnPoint(pub Option<Vec2>);
+
+fn level_entrance(
+    player: Single<&Transform, With<Player>>,
+    level_selection: Res<LevelSelection>,
+    mut respawn: ResMut<RespawnPoint>,
+) {
+    if level_selection.is_changed() || respawn.0.is_none() {
+        respawn.0 = Some(player.translation.truncate());
+    }
+}
+
+// Death waits for the deathbomb window to close
+fn check_death(
+    player: Single<(&PlayerStats, Has<PlayerDying>), With<Player>>,
+    recent_hit: Res<RecentHit>,
+    mut death_event: EventWriter<PlayerDied>,
+) {
+    let (stats, dying) = *player;
+
+    if stats.health <= 0 && !dying && !recent_hit.is_active() {
+        death_event.write(PlayerDied);
+    }
+}
+
+fn die(
+    mut cmd: Commands,
+    mut death_events: EventReader<PlayerDied>,
+    player: Single<Entity, With<Player>>,
+    mut controls: ResMut<ControlsEnabled>,
+    mut physics: ResMut<PhysicsEnabled>,
+    mut cancel_event: EventWriter<CancelBullets>,
+) {
+    if death_events.read().count() == 0 {
+        return;
+    }
+
+    controls.0 = false;
+    physics.0 = false;
+    cmd.entity(*player).insert(PlayerDying(Timer::from_seconds(
+        PLAYER_DEATH_TIME,
+        TimerMode::Once,
+    )));
+    cancel_event.write(CancelBullets {
+        area: CancelArea::Screen,
+        items: false,
+    });
+    info!("Player died");
+}
+
+fn dying(
+    player: Single<(&mut PlayerDying, &mut Sprite, &mut PlayerStats), With<Player>>,
+    mut respawn_event: EventWriter<PlayerRespawn>,
+    mut state: ResMut<NextState<AppState>>,
+    time: Res<Time>,
+) {
+    let (mut timer, mut sprite, mut stats) = player.into_inner();
+
+    // Blinks out while fading
+    let elapsed = timer.0.elapsed_secs();
+    let blink = (elapsed * PLAYER_DEATH_BLINK_FREQUENCY).sin() > 0.;
+    sprite.color = sprite.color.with_alpha(match blink {
+        true => timer.0.fraction_remaining(),
+        false => 0.,
+    });
+
+    if !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    if stats.lives <= 0 {
+        info!("Game over");
+        state.set(AppState::GameOver);
+        return;
+    }
+
+    stats.lives -= 1;
+    respawn_event.write(PlayerRespawn);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn respawn(
+    mut cmd: Commands,
+    mut respawn_events: EventReader<PlayerRespawn>,
+    player: Single<
+        (
+            Entity,
+            &mut Sprite,
+            &mut PlayerStats,
+            &mut Transform,
+            &mut Velocity,
+        ),
+        With<Player>,
+    >,
+    respawn: Res<RespawnPoint>,
+    mut invincibility: ResMut<InvincibilityTimer>,
+    mut controls: ResMut<ControlsEnabled>,
+    mut physics: ResMut<PhysicsEnabled>,
+) {
+    if respawn_events.read().count() == 0 {
+        return;
+    }
+
+    let (entity, mut sprite, mut stats, mut transform, mut velocity) = player.into_inner();
+    stats.health = PLAYER_HEALTH;
+    stats.stamina = PLAYER_STAMINA;
+    stats.bombs = PLAYER_BOMBS;
+
+    if let Some(point) = respawn.0 {
+        transform.translation = point.extend(transform.translation.z);
+    }
+    velocity.linvel = Vec2::ZERO;
+    sprite.color = sprite.color.with_alpha(1.);
+
+    invincibility.start(PLAYER_RESPAWN_INVINCIBILITY_TIME);
+    controls.0 = true;
+    physics.0 = true;
+    cmd.entity(entity).remove::<PlayerDying>();
+    info!("Player respawned, lives left: {}", stats.lives);
+}
+
+#[derive(Component)]
+struct GameOverScreen;
+
+#[derive(Component)]
+struct ContinueButton;
+
+fn game_over_screen(mut cmd: Commands) {
+    cmd.spawn((
+        GameOverScreen,
+        Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0., 0., 0., 0.6)),
+        children![
+            Text::new("Game Over"),
+            (ContinueButton, menu_button("Continue")),
+        ],
+    ));
+}
+
+// Continuing starts over with full lives from the last respawn point, the score is lost
+fn continue_button(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<ContinueButton>)>,
+    mut stats: Single<&mut PlayerStats, With<Player>>,
+    mut respawn_event: EventWriter<PlayerRespawn>,
+    mut state: ResMut<NextState<AppState>>,
+) {
+    if !buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+
+    stats.lives = PLAYER_LIVES;
+    respawn_event.write(PlayerRespawn);
+    state.set(AppState::Playing);
+}
+
+fn despawn_game_over_screen(mut cmd: Commands, screens: Query<Entity, With<GameOverScreen>>) {
+    for screen in &screens {
+        cmd.entity(screen).despawn();
+    }
+}
+
+pub struct DeathPlugin;
+
+impl Plugin for DeathPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RespawnPoint>()
+            .add_event::<PlayerRespawn>()
+            .add_systems(
+                Update,
+                (
+                    level_entrance,
+                    (
+                        check_death,
+                        die,
+                        dying.run_if(in_state(AppState::Playing)),
+                        continue_button.run_if(in_state(AppState::GameOver)),
+                        respawn,
+                    )
+                        .chain(),
+                ),
+            )
+            .add_systems(OnEnter(AppState::GameOver), game_over_screen)
+            .add_systems(OnExit(AppState::GameOver), despawn_game_over_screen);
+    }
+}
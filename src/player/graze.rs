@@ -0,0 +1,89 @@
+use bevy::ecs::entity::EntityHashSet;
+use bevy::prelude::*;
+
+use super::config::{collision::PLAYER_GRAZE_RADIUS, stats::*};
+use super::hitbox::PlayerHitbox;
+use super::stats::{ChangeStamina, PlayerStats};
+use super::Player;
+use crate::bullet::{collision::BulletGridUpdate, grid::BulletGrid};
+
+#[derive(Event)]
+pub struct Graze {
+    pub bullet: Entity,
+}
+
+// Bullets inside the graze radius, counted once they leave it without hitting the player
+#[derive(Component)]
+pub struct Grazing;
+
+// Bullets are only grazed once
+#[derive(Component)]
+pub struct Grazed;
+
+#[derive(Resource, Default)]
+pub struct GrazeCount(pub u64);
+
+fn graze(
+    mut cmd: Commands,
+    grid: Res<BulletGrid>,
+    player: Single<(&GlobalTransform, &PlayerHitbox), With<Player>>,
+    grazed: Query<(), With<Grazed>>,
+    grazing: Query<Entity, With<Grazing>>,
+    mut graze_event: EventWriter<Graze>,
+) {
+    let (transform, hitbox) = *player;
+    let center = transform.translation().truncate();
+    let rect = hitbox.rect(center);
+    let mut inside = EntityHashSet::default();
+
+    for bullet in grid.query_circle(center, PLAYER_GRAZE_RADIUS) {
+        if grazed.contains(bullet.entity) {
+            continue;
+        }
+        inside.insert(bullet.entity);
+
+        if bullet.overlaps_rect(rect) {
+            // A hit voids the graze
+            cmd.entity(bullet.entity)
+                .try_remove::<Grazing>()
+                .try_insert(Grazed);
+        } else if !grazing.contains(bullet.entity) {
+            cmd.entity(bullet.entity).try_insert(Grazing);
+        }
+    }
+
+    for bullet in grazing.iter().filter(|bullet| !inside.contains(bullet)) {
+        cmd.entity(bullet)
+            .try_remove::<Grazing>()
+            .try_insert(Grazed);
+        graze_event.write(Graze { bullet });
+    }
+}
+
+fn count_grazes(
+    mut graze_events: EventReader<Graze>,
+    mut count: ResMut<GrazeCount>,
+    stats: Single<&PlayerStats, With<Player>>,
+    mut stamina_event: EventWriter<ChangeStamina>,
+) {
+    for _ in graze_events.read() {
+        count.0 += 1;
+
+        if count.0 % PLAYER_GRAZES_PER_STAMINA == 0 && stats.stamina < PLAYER_STAMINA {
+            stamina_event.write(ChangeStamina(1));
+        }
+    }
+}
+
+pub struct GrazePlugin;
+
+impl Plugin for GrazePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Graze>()
+            .init_resource::<GrazeCount>()
+            .add_systems(
+                Update,
+                (graze.after(BulletGridUpdate), count_grazes).chain(),
+            );
+    }
+}
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::ActionState;
+
+use super::config::collision::{PLAYER_HITBOX_COLOR, PLAYER_HITBOX_SIZE, PLAYER_HITBOX_Z};
+use super::Player;
+use crate::input::Action;
+
+// Area checked against bullets, independent of the rapier collider used for terrain
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PlayerHitbox {
+    pub half_size: Vec2,
+}
+
+impl Default for PlayerHitbox {
+    fn default() -> Self {
+        PlayerHitbox {
+            half_size: Vec2::splat(PLAYER_HITBOX_SIZE / 2.),
+        }
+    }
+}
+
+impl PlayerHitbox {
+    pub fn rect(&self, center: Vec2) -> Rect {
+        Rect::from_center_half_size(center, self.half_size)
+    }
+}
+
+// Shows the hitbox even when not focusing, toggled with Action::ToggleHitbox
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ShowHitbox(pub bool);
+
+#[derive(Component)]
+pub struct HitboxSprite;
+
+fn spawn_hitbox_sprite(
+    mut cmd: Commands,
+    players: Query<(Entity, &PlayerHitbox), (Added<PlayerHitbox>, With<Player>)>,
+) {
+    for (player, hitbox) in &players {
+        cmd.spawn((
+            HitboxSprite,
+            Name::from("Hitbox"),
+            Sprite {
+                color: PLAYER_HITBOX_COLOR,
+                custom_size: Some(hitbox.half_size * 2.),
+                ..default()
+            },
+            Transform::from_xyz(0., 0., PLAYER_HITBOX_Z),
+            Visibility::Hidden,
+            ChildOf(player),
+        ));
+    }
+}
+
+fn toggle_hitbox(
+    input: Single<&ActionState<Action>, With<Player>>,
+    mut show_hitbox: ResMut<ShowHitbox>,
+) {
+    if input.just_pressed(&Action::ToggleHitbox) {
+        show_hitbox.0 = !show_hitbox.0;
+    }
+}
+
+fn hitbox_visibility(
+    input: Single<&ActionState<Action>, With<Player>>,
+    mut sprites: Query<&mut Visibility, With<HitboxSprite>>,
+    show_hitbox: Res<ShowHitbox>,
+) {
+    let visible = show_hitbox.0 || input.pressed(&Action::Focus);
+
+    for mut visibility in &mut sprites {
+        visibility.set_if_neq(match visible {
+            true => Visibility::Visible,
+            false => Visibility::Hidden,
+        });
+    }
+}
+
+pub struct PlayerHitboxPlugin;
+
+impl Plugin for PlayerHitboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ShowHitbox>()
+            .init_resource::<ShowHitbox>()
+            .add_systems(
+                Update,
+                (
+                    spawn_hitbox_sprite,
+                    (toggle_hitbox, hitbox_visibility).chain(),
+                ),
+            );
+    }
+}
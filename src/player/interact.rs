@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::ActionState;
+
+use super::config::collision::PLAYER_INTERACT_RADIUS;
+use super::{controls_enabled, Player};
+use crate::input::Action;
+
+// Can be used with the interact action when the player stands close enough
+#[derive(Component, Debug, Default)]
+pub struct Interactable;
+
+#[derive(Event, Debug)]
+pub struct Interact(pub Entity);
+
+// Only the closest object in reach is used
+fn interact(
+    player: Single<(&ActionState<Action>, &GlobalTransform), With<Player>>,
+    interactables: Query<(Entity, &GlobalTransform), With<Interactable>>,
+    mut interact_event: EventWriter<Interact>,
+) {
+    let (input, player_transform) = *player;
+    if !input.just_pressed(&Action::Interact) {
+        return;
+    }
+
+    let player_position = player_transform.translation().truncate();
+    let closest = interactables
+        .iter()
+        .map(|(target, transform)| {
+            let distance = transform.translation().truncate().distance(player_position);
+            (target, distance)
+        })
+        .filter(|(_, distance)| *distance <= PLAYER_INTERACT_RADIUS)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    if let Some((target, _)) = closest {
+        interact_event.write(Interact(target));
+    }
+}
+
+pub struct InteractPlugin;
+
+impl Plugin for InteractPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Interact>()
+            .add_systems(Update, interact.run_if(controls_enabled));
+    }
+}
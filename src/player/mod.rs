@@ -1,4 +1,5 @@
 use crate::input::Action;
+use crate::item::Inventory;
 use crate::physics::looking_direction::LookDir;
 use crate::player::state::{PlayerState, StatePlugin};
 use crate::{colliders::ColliderBundle, ground_detection::GroundDetection};
@@ -17,7 +18,15 @@ pub mod stats;
 use stats::{PlayerStats, PlayerStatsPlugin};
 pub mod attack;
 use attack::AttackPlugin;
+pub mod death;
+use death::DeathPlugin;
+pub mod graze;
+use graze::GrazePlugin;
+pub mod hitbox;
+pub mod interact;
+use interact::InteractPlugin;
 pub mod state;
+use hitbox::{PlayerHitbox, PlayerHitboxPlugin};
 
 #[derive(Debug, Default, Component)]
 pub struct Player;
@@ -46,8 +55,11 @@ pub struct PlayerBundle {
     // pub sprite: Sprite,
     // pub animation: Aseprite,
     pub stats: PlayerStats,
+    #[with(Inventory::from_field)]
+    pub inventory: Inventory,
     #[from_entity_instance]
     pub collider_bundle: ColliderBundle,
+    pub hitbox: PlayerHitbox,
     pub player: Player,
     pub looking_direction: LookingDirection,
     #[worldly]
@@ -86,6 +98,10 @@ impl Plugin for PlayerPlugin {
             .add_plugins(PlayerAnimationPlugin)
             .add_plugins(PlayerStatsPlugin)
             .add_plugins(AttackPlugin)
+            .add_plugins(PlayerHitboxPlugin)
+            .add_plugins(GrazePlugin)
+            .add_plugins(DeathPlugin)
+            .add_plugins(InteractPlugin)
             .add_plugins(StatePlugin);
     }
 }
@@ -3,7 +3,9 @@ use crate::{
     ground_detection::GroundDetection,
     input::Action,
     physics::looking_direction::LookDir,
-    player::{physics_disabled, physics_enabled, DashTimer, LookingDirection, Player},
+    player::{
+        physics_disabled, physics_enabled, state::PlayerState, DashTimer, LookingDirection, Player,
+    },
     shared::{move_toward_f32, move_toward_vec2},
 };
 use bevy::prelude::*;
@@ -51,14 +53,16 @@ fn player_dash(
 }
 
 fn player_gravity(
-    mut player: Single<&mut Velocity, With<Player>>,
+    player: Single<(&mut Velocity, &PlayerState), With<Player>>,
     time: Res<Time>,
     mut dash_timer: ResMut<DashTimer>,
 ) {
+    let (mut velocity, state) = player.into_inner();
     let delta = time.delta_secs_f64();
-    let velocity = &mut player.linvel;
+    let velocity = &mut velocity.linvel;
 
-    if dash_timer.0.tick(time.delta()).finished() {
+    // The dash timer keeps running on ladders, there's just no gravity to hold back
+    if dash_timer.0.tick(time.delta()).finished() && *state != PlayerState::Climb {
         velocity.y -= (PLAYER_GRAVITY * delta) as f32;
     }
 }
@@ -79,11 +83,19 @@ fn player_decelleration(mut player: Single<&mut Velocity, With<Player>>, time: R
 }
 
 fn player_jump(
-    player: Single<(&ActionState<Action>, &mut Velocity, &GroundDetection), With<Player>>,
+    player: Single<
+        (
+            &ActionState<Action>,
+            &mut Velocity,
+            &GroundDetection,
+            &PlayerState,
+        ),
+        With<Player>,
+    >,
 ) {
-    let (input, mut velocity, GroundDetection { grounded, .. }) = player.into_inner();
+    let (input, mut velocity, GroundDetection { grounded, .. }, state) = player.into_inner();
 
-    if input.pressed(&Action::Jump) && *grounded {
+    if input.pressed(&Action::Jump) && (*grounded || *state == PlayerState::Climb) {
         velocity.linvel.y = PLAYER_JUMP_STRENGTH;
     }
 }
@@ -96,8 +108,13 @@ fn player_horizontal_movement(
     let velocity = &mut velocity.linvel;
     let direction = input.axis_pair(&Action::Direction);
 
+    let speed = match input.pressed(&Action::Focus) {
+        true => PLAYER_SPEED * PLAYER_FOCUS_SPEED_SCALE,
+        false => PLAYER_SPEED,
+    };
+
     let delta = time.delta().as_secs_f64();
-    let new_vel_x = direction.x * PLAYER_SPEED;
+    let new_vel_x = direction.x * speed;
     let acceleration = PLAYER_ACELLERATION * delta;
 
     if direction.x != 0. && new_vel_x.abs() > velocity.x.abs() {
@@ -105,6 +122,19 @@ fn player_horizontal_movement(
     }
 }
 
+// Replaces the usual movement while on a ladder
+fn player_climb(player: Single<(&ActionState<Action>, &PlayerState, &mut Velocity), With<Player>>) {
+    let (input, state, mut velocity) = player.into_inner();
+
+    // Jumping off takes over until the state changes
+    if *state != PlayerState::Climb || input.pressed(&Action::Jump) {
+        return;
+    }
+
+    let direction = input.axis_pair(&Action::Direction);
+    velocity.linvel = direction * Vec2::new(PLAYER_CLIMB_SIDE_SPEED, PLAYER_CLIMB_SPEED);
+}
+
 fn player_looking_direction(
     player: Single<(&ActionState<Action>, &mut LookingDirection), With<Player>>,
 ) {
@@ -153,7 +183,11 @@ impl Plugin for PlayerPhysicsPlugin {
                     player_horizontal_movement.run_if(controls_enabled),
                     player_looking_direction.run_if(controls_enabled),
                     player_autostep.run_if(physics_enabled),
-                    player_decelleration.run_if(physics_enabled),
+                    (
+                        player_decelleration.run_if(physics_enabled),
+                        player_climb.run_if(controls_enabled),
+                    )
+                        .chain(),
                     stop.run_if(physics_disabled),
                 ),
             );
@@ -1,11 +1,13 @@
 use super::animation::sprites;
 use bevy::prelude::*;
 use bevy_mod_aseprite::{Aseprite, AsepriteAsset, AsepriteTag};
+use bevy_rapier2d::prelude::ReadRapierContext;
 use leafwing_input_manager::prelude::ActionState;
 
 use crate::{
     input::Action,
-    player::{ControlsEnabled, PhysicsEnabled, Player},
+    ladder::LadderSensor,
+    player::{death::PlayerDying, ControlsEnabled, PhysicsEnabled, Player},
 };
 
 // #[derive(Default)]
@@ -17,6 +19,7 @@ pub enum PlayerState {
     Idle,
     Walk,
     Attack,
+    Climb,
 }
 
 #[derive(Debug, Component)]
@@ -35,6 +38,7 @@ impl PlayerState {
             S::Idle => false,
             S::Walk => false,
             S::Attack => true,
+            S::Climb => false,
         }
     }
     pub fn has_controls_priority(&self) -> bool {
@@ -43,6 +47,7 @@ impl PlayerState {
             S::Idle => false,
             S::Walk => false,
             S::Attack => true,
+            S::Climb => false,
         }
     }
     // Overrides control priority
@@ -52,6 +57,7 @@ impl PlayerState {
             S::Idle => false,
             S::Walk => false,
             S::Attack => true,
+            S::Climb => false,
         }
     }
     pub fn animation_tag(&self) -> AsepriteTag {
@@ -59,6 +65,8 @@ impl PlayerState {
             Self::Idle => sprites::Player::tags::IDLE,
             Self::Walk => sprites::Player::tags::WALK,
             Self::Attack => sprites::Player::tags::ATTACK,
+            // reimu.aseprite has no climb tag, climbing falls back to idle
+            Self::Climb => sprites::Player::tags::IDLE,
         }
         .into()
     }
@@ -107,22 +115,27 @@ fn change_state(
     mut events: EventReader<ChangePlayerState>,
     mut controls_enabled: ResMut<ControlsEnabled>,
     mut physics_enabled: ResMut<PhysicsEnabled>,
-    player: Single<&mut PlayerState, With<Player>>,
+    player: Single<(&mut PlayerState, Has<PlayerDying>), With<Player>>,
 ) {
-    let mut state = player.into_inner();
+    let (mut state, dying) = player.into_inner();
     for event in events.read() {
         *state = event.0.clone();
-        controls_enabled.0 = !event.0.has_controls_priority();
-        physics_enabled.0 = !event.0.has_physics_priority();
+        // Death keeps the player frozen until the respawn
+        controls_enabled.0 = !dying && !event.0.has_controls_priority();
+        physics_enabled.0 = !dying && !event.0.has_physics_priority();
     }
 }
 
 fn walking_state(
-    player: Single<(&ActionState<Action>, &PlayerState), With<Player>>,
+    player: Single<(&ActionState<Action>, &PlayerState), (With<Player>, Without<PlayerDying>)>,
     mut event: EventWriter<QueuePlayerState>,
 ) {
     let (input, state) = player.into_inner();
 
+    if *state == PlayerState::Climb {
+        return;
+    }
+
     let direction = input.axis_pair(&Action::Direction);
     use PlayerState as S;
     let new_state = match direction.x {
@@ -134,13 +147,44 @@ fn walking_state(
     }
 }
 
+// Grabs a ladder when moving up or down on it, lets go when leaving it or jumping off
+fn climbing_state(
+    rapier_context: ReadRapierContext,
+    player: Single<
+        (Entity, &ActionState<Action>, &PlayerState),
+        (With<Player>, Without<PlayerDying>),
+    >,
+    ladders: Query<Entity, With<LadderSensor>>,
+    mut event: EventWriter<QueuePlayerState>,
+) -> Result {
+    let context = rapier_context.single()?;
+    let (player, input, state) = player.into_inner();
+
+    let on_ladder = ladders
+        .iter()
+        .any(|ladder| context.intersection_pair(ladder, player) == Some(true));
+    let direction = input.axis_pair(&Action::Direction);
+
+    use PlayerState as S;
+    match *state {
+        S::Climb if !on_ladder || input.just_pressed(&Action::Jump) => {
+            event.write(QueuePlayerState(S::Idle));
+        }
+        S::Idle | S::Walk if on_ladder && direction.y != 0. && !input.pressed(&Action::Jump) => {
+            event.write(QueuePlayerState(S::Climb));
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
 pub(super) struct StatePlugin;
 
 impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Events<QueuePlayerState>>()
             .init_resource::<Events<ChangePlayerState>>()
-            .add_systems(Update, walking_state)
+            .add_systems(Update, (walking_state, climbing_state).chain())
             .add_systems(Update, queue_state)
             .add_systems(Update, change_state)
             .add_systems(Update, apply_state_buf);
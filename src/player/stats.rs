@@ -1,10 +1,14 @@
-use super::{config::stats::*, physics::Dash, Player};
+use super::{config::stats::*, death::PlayerDying, physics::Dash, Player};
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
-#[derive(Component)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerStats {
     pub health: i64,
     pub stamina: i64,
+    pub lives: i64,
+    pub bombs: i64,
 }
 
 impl Default for PlayerStats {
@@ -12,6 +16,8 @@ impl Default for PlayerStats {
         PlayerStats {
             health: PLAYER_HEALTH,
             stamina: PLAYER_STAMINA,
+            lives: PLAYER_LIVES,
+            bombs: PLAYER_BOMBS,
         }
     }
 }
@@ -22,14 +28,76 @@ pub struct ChangeHealth(pub i64);
 #[derive(Event, Default)]
 pub struct ChangeStamina(pub i64);
 
+#[derive(Event, Default)]
+pub struct PlayerDied;
+
+// Damage that went through invincibility, unlike a raw ChangeHealth
+#[derive(Event, Default)]
+pub struct PlayerHit;
+
 #[derive(Resource)]
 pub struct InvincibilityTimer(Timer);
 
+impl InvincibilityTimer {
+    pub fn start(&mut self, secs: f32) {
+        self.0.set_duration(Duration::from_secs_f32(secs));
+        self.0.reset();
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.0.finished()
+    }
+}
+
+// Damage taken recently enough to be cancelled by a deathbomb
+#[derive(Resource)]
+pub struct RecentHit {
+    timer: Timer,
+    amount: i64,
+}
+
+impl Default for RecentHit {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(PLAYER_DEATHBOMB_WINDOW, TimerMode::Once);
+        timer.tick(timer.duration());
+        RecentHit { timer, amount: 0 }
+    }
+}
+
+impl RecentHit {
+    fn hit(&mut self, amount: i64) {
+        if self.timer.finished() {
+            self.amount = 0;
+        }
+        self.amount += amount;
+        self.timer.reset();
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.timer.finished()
+    }
+
+    // Returns the damage to give back
+    pub fn cancel(&mut self) -> i64 {
+        if !self.is_active() {
+            return 0;
+        }
+        let duration = self.timer.duration();
+        self.timer.tick(duration);
+        std::mem::take(&mut self.amount)
+    }
+}
+
 #[derive(Resource)]
 pub struct StaminaTimer(Timer);
 
-fn player_invincibility_timer(mut invicibility_timer: ResMut<InvincibilityTimer>, time: Res<Time>) {
+fn player_invincibility_timer(
+    mut invicibility_timer: ResMut<InvincibilityTimer>,
+    mut recent_hit: ResMut<RecentHit>,
+    time: Res<Time>,
+) {
     invicibility_timer.0.tick(time.delta());
+    recent_hit.timer.tick(time.delta());
 }
 
 fn decrease_stamina_on_dash(
@@ -66,20 +134,35 @@ pub fn stamina_change_event(
     mut reader: EventReader<ChangeStamina>,
 ) {
     for event in reader.read() {
-        player.stamina += event.0;
+        // Grazes and regen can both refill stamina in the same frame
+        player.stamina = (player.stamina + event.0).min(PLAYER_STAMINA);
         info!("Stamina: {}", player.stamina);
     }
 }
 
 fn health_change_event(
-    mut player: Single<&mut PlayerStats, With<Player>>,
+    player: Single<(&mut PlayerStats, Has<PlayerDying>), With<Player>>,
     mut invicibility_timer: ResMut<InvincibilityTimer>,
     mut reader: EventReader<ChangeHealth>,
+    mut recent_hit: ResMut<RecentHit>,
+    mut hit_event: EventWriter<PlayerHit>,
 ) {
+    let (mut player, dying) = player.into_inner();
+
+    if dying {
+        reader.clear();
+        return;
+    }
+
     if invicibility_timer.0.finished() {
         for event in reader.read() {
-            player.health += event.0;
-            invicibility_timer.0.reset();
+            let health = (player.health + event.0).clamp(0, PLAYER_HEALTH);
+            if health < player.health {
+                recent_hit.hit(player.health - health);
+                hit_event.write(PlayerHit);
+            }
+            player.health = health;
+            invicibility_timer.start(PLAYER_INVINCIBILITY_TIME);
             info!("Health: {}", player.health);
         }
     }
@@ -91,10 +174,13 @@ impl Plugin for PlayerStatsPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<ChangeHealth>()
             .add_event::<ChangeStamina>()
+            .add_event::<PlayerDied>()
+            .add_event::<PlayerHit>()
             .insert_resource(InvincibilityTimer(Timer::from_seconds(
                 PLAYER_INVINCIBILITY_TIME,
                 TimerMode::Once,
             )))
+            .init_resource::<RecentHit>()
             .insert_resource(StaminaTimer(Timer::from_seconds(
                 PLAYER_STAMINA_REGEN_TIME,
                 TimerMode::Once,
@@ -106,3 +192,42 @@ impl Plugin for PlayerStatsPlugin {
             .add_systems(Update, stamina_regen);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tick(recent_hit: &mut RecentHit, secs: f32) {
+        recent_hit.timer.tick(Duration::from_secs_f32(secs));
+    }
+
+    #[test]
+    fn deathbomb_window() {
+        let mut recent_hit = RecentHit::default();
+        assert!(!recent_hit.is_active());
+        assert_eq!(recent_hit.cancel(), 0);
+
+        recent_hit.hit(1);
+        tick(&mut recent_hit, PLAYER_DEATHBOMB_WINDOW / 2.);
+        recent_hit.hit(2);
+        tick(&mut recent_hit, PLAYER_DEATHBOMB_WINDOW / 2.);
+        assert!(recent_hit.is_active());
+
+        // Both hits are given back, only once
+        assert_eq!(recent_hit.cancel(), 3);
+        assert_eq!(recent_hit.cancel(), 0);
+    }
+
+    #[test]
+    fn deathbomb_too_late() {
+        let mut recent_hit = RecentHit::default();
+        recent_hit.hit(1);
+        tick(&mut recent_hit, PLAYER_DEATHBOMB_WINDOW * 2.);
+        assert!(!recent_hit.is_active());
+        assert_eq!(recent_hit.cancel(), 0);
+
+        // Damage from a closed window isn't carried over
+        recent_hit.hit(1);
+        assert_eq!(recent_hit.cancel(), 1);
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+
+use super::SaveGame;
+use crate::config::save::*;
+use crate::player::death::RespawnPoint;
+use crate::player::Player;
+
+#[derive(Component, Debug, Default)]
+pub struct Checkpoint {
+    pub half_size: Vec2,
+}
+
+impl From<&EntityInstance> for Checkpoint {
+    fn from(entity_instance: &EntityInstance) -> Self {
+        Checkpoint {
+            half_size: Vec2::new(entity_instance.width as f32, entity_instance.height as f32) / 2.,
+        }
+    }
+}
+
+// Checkpoint the player last saved at
+#[derive(Resource, Debug, Default)]
+pub struct LastCheckpoint(pub Option<ReachedCheckpoint>);
+
+#[derive(Debug, Clone)]
+pub struct ReachedCheckpoint {
+    pub iid: EntityIid,
+    pub level: LevelSelection,
+    // Where the player stood when reaching it
+    pub position: Vec2,
+}
+
+fn sprite(entity_instance: &EntityInstance) -> Sprite {
+    Sprite::from_color(
+        CHECKPOINT_COLOR,
+        Vec2::new(entity_instance.width as f32, entity_instance.height as f32),
+    )
+}
+
+#[derive(Bundle, Default, LdtkEntity)]
+pub struct CheckpointBundle {
+    #[from_entity_instance]
+    pub checkpoint: Checkpoint,
+    #[with(sprite)]
+    pub sprite: Sprite,
+    #[from_entity_instance]
+    pub entity_instance: EntityInstance,
+}
+
+fn reach_checkpoint(
+    player: Single<(&GlobalTransform, &Transform), With<Player>>,
+    mut checkpoints: Query<(&EntityIid, &Checkpoint, &GlobalTransform, &mut Sprite)>,
+    level_selection: Res<LevelSelection>,
+    mut last: ResMut<LastCheckpoint>,
+    mut respawn: ResMut<RespawnPoint>,
+    mut save_event: EventWriter<SaveGame>,
+) {
+    let (player_global, player_transform) = *player;
+    let position = player_global.translation().truncate();
+
+    for (iid, checkpoint, transform, mut sprite) in &mut checkpoints {
+        if last.0.as_ref().is_some_and(|last| last.iid == *iid) {
+            sprite.color = CHECKPOINT_REACHED_COLOR;
+            continue;
+        }
+        sprite.color = CHECKPOINT_COLOR;
+
+        let area =
+            Rect::from_center_half_size(transform.translation().truncate(), checkpoint.half_size);
+        if !area.contains(position) {
+            continue;
+        }
+
+        let respawn_point = player_transform.translation.truncate();
+        last.0 = Some(ReachedCheckpoint {
+            iid: iid.clone(),
+            level: (*level_selection).clone(),
+            position: respawn_point,
+        });
+        respawn.0 = Some(respawn_point);
+        save_event.write(SaveGame);
+        info!("Checkpoint reached");
+    }
+}
+
+pub struct CheckpointPlugin;
+
+impl Plugin for CheckpointPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_ldtk_entity::<CheckpointBundle>("Checkpoint")
+            .init_resource::<LastCheckpoint>()
+            .add_systems(Update, reach_checkpoint);
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+use std::fs;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::save::SAVE_FILE;
+use crate::door::OpenedDoors;
+use crate::enemy::boss::{BossDefeated, DefeatedBosses};
+use crate::item::chest::OpenedChests;
+use crate::player::death::RespawnPoint;
+use crate::player::stats::PlayerStats;
+use crate::player::Player;
+use crate::shared::data_path;
+
+pub mod checkpoint;
+use checkpoint::{CheckpointPlugin, LastCheckpoint, ReachedCheckpoint};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SaveData {
+    // Level of the last checkpoint, or the current one if none was reached yet
+    pub level: String,
+    // Where the player stood when the last checkpoint was reached
+    pub checkpoint: Option<Vec2>,
+    #[serde(default)]
+    pub checkpoint_iid: Option<String>,
+    pub stats: PlayerStats,
+    pub defeated_bosses: Vec<String>,
+    pub opened_chests: Vec<String>,
+    pub opened_doors: Vec<String>,
+}
+
+impl SaveData {
+    pub fn exists() -> bool {
+        data_path(SAVE_FILE).is_some_and(|path| path.exists())
+    }
+
+    pub fn load() -> Option<Self> {
+        let path = data_path(SAVE_FILE)?;
+        let contents = fs::read_to_string(&path).ok()?;
+
+        match ron::from_str(&contents) {
+            Ok(save) => Some(save),
+            Err(error) => {
+                error!("Couldn't read save from {}: {error}", path.display());
+                None
+            }
+        }
+    }
+}
+
+#[derive(Event, Debug, Default)]
+pub struct SaveGame;
+
+// Save chosen in the main menu, applied once the player spawns
+#[derive(Resource, Debug)]
+pub struct PendingLoad(pub SaveData);
+
+fn iids(set: &HashSet<EntityIid>) -> Vec<String> {
+    set.iter().map(ToString::to_string).collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn save_game(
+    mut save_events: EventReader<SaveGame>,
+    player: Single<&PlayerStats, With<Player>>,
+    level_selection: Res<LevelSelection>,
+    checkpoint: Res<LastCheckpoint>,
+    bosses: Res<DefeatedBosses>,
+    chests: Res<OpenedChests>,
+    doors: Res<OpenedDoors>,
+) {
+    if save_events.read().count() == 0 {
+        return;
+    }
+
+    let checkpoint = checkpoint.0.as_ref();
+    let level_selection =
+        checkpoint.map_or(level_selection.as_ref(), |checkpoint| &checkpoint.level);
+    let LevelSelection::Iid(level) = level_selection else {
+        error!("Couldn't save, the level isn't selected by iid");
+        return;
+    };
+
+    let save = SaveData {
+        level: level.to_string(),
+        checkpoint: checkpoint.map(|checkpoint| checkpoint.position),
+        checkpoint_iid: checkpoint.map(|checkpoint| checkpoint.iid.to_string()),
+        stats: player.clone(),
+        defeated_bosses: iids(&bosses.0),
+        opened_chests: iids(&chests.0),
+        opened_doors: iids(&doors.0),
+    };
+
+    let Some(path) = data_path(SAVE_FILE) else {
+        error!("Couldn't find a data directory to save the game in");
+        return;
+    };
+
+    let saved = ron::ser::to_string_pretty(&save, default())
+        .map_err(std::io::Error::other)
+        .and_then(|contents| {
+            path.parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(&path, contents))
+        });
+
+    match saved {
+        Ok(()) => info!("Game saved"),
+        Err(error) => error!("Couldn't save the game to {}: {error}", path.display()),
+    }
+}
+
+// Progress outside of checkpoints is saved as soon as it's made, the first run only sees the
+// resources being initialized
+fn autosave(
+    mut boss_events: EventReader<BossDefeated>,
+    chests: Res<OpenedChests>,
+    doors: Res<OpenedDoors>,
+    mut save_event: EventWriter<SaveGame>,
+) {
+    let bosses = boss_events.read().count() > 0;
+    let chests = chests.is_changed() && !chests.is_added();
+    let doors = doors.is_changed() && !doors.is_added();
+
+    if bosses || chests || doors {
+        save_event.write(SaveGame);
+    }
+}
+
+// The player's own level has to spawn first, the saved one is selected afterwards
+#[allow(clippy::too_many_arguments)]
+fn load_game(
+    mut cmd: Commands,
+    pending: Option<Res<PendingLoad>>,
+    player: Single<(&mut PlayerStats, &mut Transform), Added<Player>>,
+    mut level_selection: ResMut<LevelSelection>,
+    mut respawn: ResMut<RespawnPoint>,
+    mut last_checkpoint: ResMut<LastCheckpoint>,
+    mut bosses: ResMut<DefeatedBosses>,
+    mut chests: ResMut<OpenedChests>,
+    mut doors: ResMut<OpenedDoors>,
+) {
+    let Some(pending) = pending else {
+        return;
+    };
+    let PendingLoad(save) = pending.as_ref();
+    let (mut stats, mut transform) = player.into_inner();
+
+    *level_selection = LevelSelection::iid(save.level.clone());
+    if let Some(checkpoint) = save.checkpoint {
+        transform.translation = checkpoint.extend(transform.translation.z);
+    }
+    respawn.0 = save.checkpoint;
+    if let (Some(iid), Some(position)) = (&save.checkpoint_iid, save.checkpoint) {
+        last_checkpoint.0 = Some(ReachedCheckpoint {
+            iid: EntityIid::new(iid.clone()),
+            level: (*level_selection).clone(),
+            position,
+        });
+    }
+    *stats = save.stats.clone();
+
+    // Touching the sets for nothing would trigger an autosave
+    if !save.defeated_bosses.is_empty() {
+        bosses
+            .0
+            .extend(save.defeated_bosses.iter().cloned().map(EntityIid::new));
+    }
+    if !save.opened_chests.is_empty() {
+        chests
+            .0
+            .extend(save.opened_chests.iter().cloned().map(EntityIid::new));
+    }
+    if !save.opened_doors.is_empty() {
+        doors
+            .0
+            .extend(save.opened_doors.iter().cloned().map(EntityIid::new));
+    }
+
+    cmd.remove_resource::<PendingLoad>();
+    info!("Game loaded");
+}
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SaveGame>()
+            .add_plugins(CheckpointPlugin)
+            .add_systems(Update, (load_game, (autosave, save_game).chain()));
+    }
+}
@@ -0,0 +1,108 @@
+use std::fs;
+
+use bevy::prelude::*;
+
+use crate::bullet::cancel::PointCollected;
+use crate::config::score::*;
+use crate::enemy::boss::BossDefeated;
+use crate::enemy::health::EnemyKilled;
+use crate::player::graze::Graze;
+use crate::shared::data_path;
+use crate::spell_card::SpellCardCaptured;
+use crate::state::AppState;
+
+#[derive(Resource, Default, Debug)]
+pub struct Score(pub u64);
+
+#[derive(Resource, Default, Debug)]
+pub struct HiScore(pub u64);
+
+#[derive(Event, Debug)]
+pub struct AddScore(pub u64);
+
+fn load_hi_score(mut hi_score: ResMut<HiScore>) {
+    let Some(path) = data_path(HI_SCORE_FILE) else {
+        return;
+    };
+
+    // Missing file means nothing was saved yet
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return;
+    };
+
+    match contents.trim().parse() {
+        Ok(score) => hi_score.0 = score,
+        Err(error) => error!("Couldn't read hi-score from {}: {error}", path.display()),
+    }
+}
+
+// Saved when a run ends, either through a game over or by closing the game
+fn save_hi_score(hi_score: Res<HiScore>) {
+    let Some(path) = data_path(HI_SCORE_FILE) else {
+        error!("Couldn't find a data directory to save the hi-score in");
+        return;
+    };
+
+    let saved = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, hi_score.0.to_string()));
+
+    if let Err(error) = saved {
+        error!("Couldn't save hi-score to {}: {error}", path.display());
+    }
+}
+
+fn score_sources(
+    mut grazes: EventReader<Graze>,
+    mut points: EventReader<PointCollected>,
+    mut captures: EventReader<SpellCardCaptured>,
+    mut kills: EventReader<EnemyKilled>,
+    mut bosses: EventReader<BossDefeated>,
+    mut score_event: EventWriter<AddScore>,
+) {
+    let score = grazes.read().count() as u64 * SCORE_GRAZE
+        + points.read().count() as u64 * SCORE_POINT_ITEM
+        + captures.read().count() as u64 * SCORE_CARD_CAPTURE
+        + kills.read().count() as u64 * SCORE_ENEMY_KILLED
+        + bosses.read().count() as u64 * SCORE_BOSS_DEFEATED;
+
+    if score > 0 {
+        score_event.write(AddScore(score));
+    }
+}
+
+fn add_score(
+    mut score_events: EventReader<AddScore>,
+    mut score: ResMut<Score>,
+    mut hi_score: ResMut<HiScore>,
+) {
+    for AddScore(amount) in score_events.read() {
+        score.0 += amount;
+    }
+
+    if score.0 > hi_score.0 {
+        hi_score.0 = score.0;
+    }
+}
+
+// Every run begins at zero, whether it's started from the menu or continued after a game over
+fn reset_score(mut score: ResMut<Score>) {
+    score.0 = 0;
+}
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AddScore>()
+            .init_resource::<Score>()
+            .init_resource::<HiScore>()
+            .add_systems(Startup, load_hi_score)
+            .add_systems(Update, (score_sources, add_score).chain())
+            .add_systems(OnEnter(AppState::LoadingLevel), reset_score)
+            .add_systems(OnExit(AppState::GameOver), reset_score)
+            .add_systems(OnEnter(AppState::GameOver), save_hi_score)
+            .add_systems(Last, save_hi_score.run_if(on_event::<AppExit>));
+    }
+}
@@ -1,6 +1,7 @@
-use std::f64::consts::PI;
+use std::{f64::consts::PI, path::PathBuf, time::Duration};
 
 use bevy::{math::IVec2, prelude::Vec2};
+use serde::{de::Error, Deserialize, Deserializer};
 
 pub const fn ldtk_to_bevy_vec2(from: IVec2) -> Vec2 {
     Vec2 {
@@ -68,3 +69,14 @@ pub fn move_toward_quad_f32(from: f32, to: f32, easing: f64, min_speed: f64, del
         ((distance * easing).powi(2)).max(min_speed) * delta,
     )
 }
+
+/// Reads a `Duration` written as seconds, e.g. `construction_frequency: 0.01`
+pub fn deserialize_secs<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    let secs = f64::deserialize(deserializer)?;
+    Duration::try_from_secs_f64(secs).map_err(D::Error::custom)
+}
+
+// Location of files kept between runs, like the hi-score
+pub fn data_path(file: &str) -> Option<PathBuf> {
+    Some(dirs::data_dir()?.join(env!("CARGO_PKG_NAME")).join(file))
+}
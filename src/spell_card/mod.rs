@@ -1,53 +1,129 @@
-use std::{str::FromStr, time::Duration};
+use std::time::Duration;
 
-use bevy::prelude::*;
+use bevy::{ecs::system::EntityCommands, platform::collections::HashMap, prelude::*};
 use thiserror::Error;
 
+use crate::player::stats::PlayerHit;
+
 pub mod circle_of_fifth;
 use circle_of_fifth::CirclesOfFifthPlugin;
 
 #[derive(Debug, Error)]
-#[error("the given SpellCard doesn't exist")]
-pub struct NoSuchSpellCard;
+#[error("spell card \"{name}\" doesn't exist, known spell cards: {}", .known.join(", "))]
+pub struct NoSuchSpellCard {
+    pub name: String,
+    pub known: Vec<&'static str>,
+}
 
-pub enum SpellCard {
-    CirclesOfFifth,
+// Registered by each spell card's plugin, looked up by the LDtk `SpellCard` enum value
+pub struct SpellCardEntry {
+    pub name: &'static str,
+    pub title: &'static str,
+    pub duration: Duration,
+    pub health: i64,
+    pub spawn: fn(&mut EntityCommands, &SpellCardEntry),
 }
 
-impl FromStr for SpellCard {
-    type Err = NoSuchSpellCard;
+#[derive(Resource, Default)]
+pub struct SpellCardRegistry(HashMap<&'static str, SpellCardEntry>);
 
-    fn from_str(string: &str) -> Result<Self, Self::Err> {
-        use SpellCard::*;
-        match string {
-            "CirclesOfFifth" => Ok(CirclesOfFifth),
-            _ => Err(NoSuchSpellCard),
-        }
+impl SpellCardRegistry {
+    pub fn get(&self, name: &str) -> Result<&SpellCardEntry, NoSuchSpellCard> {
+        self.0.get(name).ok_or_else(|| {
+            let mut known = self.0.keys().copied().collect::<Vec<_>>();
+            known.sort();
+            NoSuchSpellCard {
+                name: name.to_string(),
+                known,
+            }
+        })
+    }
+}
+
+pub trait RegisterSpellCard {
+    fn register_spell_card(&mut self, entry: SpellCardEntry) -> &mut Self;
+}
+
+impl RegisterSpellCard for App {
+    fn register_spell_card(&mut self, entry: SpellCardEntry) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<SpellCardRegistry>()
+            .0
+            .insert(entry.name, entry);
+        self
     }
 }
 
 #[derive(Component, Default)]
 pub struct SpellCardTimer(Timer);
 
+#[derive(Event)]
+pub struct SpellCardExpired(pub Entity);
+
+#[derive(Event)]
+pub struct SpellCardDeclared {
+    pub card: Entity,
+    pub title: &'static str,
+}
+
+// Card beaten before its time ran out, without getting hit
+#[derive(Event, Default)]
+pub struct SpellCardCaptured;
+
+// Lost once the player gets hit during the card
+#[derive(Component)]
+pub struct CaptureBonus(pub bool);
+
+impl Default for CaptureBonus {
+    fn default() -> Self {
+        CaptureBonus(true)
+    }
+}
+
 impl SpellCardTimer {
     fn new(duration: Duration) -> Self {
         SpellCardTimer(Timer::new(duration, TimerMode::Once))
     }
 
-    fn tick(mut timers: Query<(&mut SpellCardTimer, Entity)>, time: Res<Time>, mut cmd: Commands) {
+    pub fn remaining(&self) -> Duration {
+        self.0.remaining()
+    }
+
+    fn tick(
+        mut timers: Query<(&mut SpellCardTimer, Entity)>,
+        time: Res<Time>,
+        mut cmd: Commands,
+        mut expired_event: EventWriter<SpellCardExpired>,
+    ) {
         for (mut timer, card) in &mut timers {
             if timer.0.tick(time.delta()).finished() {
                 cmd.entity(card).despawn();
+                expired_event.write(SpellCardExpired(card));
             }
         }
     }
 }
 
+fn lose_capture_bonus(mut hit_events: EventReader<PlayerHit>, mut cards: Query<&mut CaptureBonus>) {
+    if hit_events.read().count() == 0 {
+        return;
+    }
+
+    for mut bonus in &mut cards {
+        bonus.0 = false;
+    }
+}
+
 pub struct SpellCardPlugin;
 
 impl Plugin for SpellCardPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(CirclesOfFifthPlugin)
-            .add_systems(Update, SpellCardTimer::tick);
+        app.add_event::<SpellCardExpired>()
+            .add_event::<SpellCardDeclared>()
+            .add_event::<SpellCardCaptured>()
+            .init_resource::<SpellCardRegistry>()
+            .add_plugins(CirclesOfFifthPlugin)
+            .add_systems(Update, SpellCardTimer::tick)
+            .add_systems(Update, lose_capture_bonus);
     }
 }
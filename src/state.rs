@@ -4,10 +4,12 @@ use bevy::{asset::LoadState, prelude::*};
 #[derive(States, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub enum AppState {
     #[default]
+    MainMenu,
     LoadingLevel,
     LoadingLevelAssets,
     Playing,
     Pause,
+    GameOver,
 }
 
 fn check_assets(
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use bevy::{platform::collections::HashMap, prelude::*};
+use bevy::{ecs::query::QueryFilter, platform::collections::HashMap, prelude::*};
 use bevy_ecs_ldtk::prelude::*;
 
 use bevy_rapier2d::prelude::*;
@@ -13,6 +13,157 @@ pub struct WallBundle {
     wall: Wall,
 }
 
+/// Represents a wide wall that is 1 tile tall
+/// Used to spawn wall collisions
+#[derive(Clone, Eq, PartialEq, Debug, Default, Hash)]
+struct Plate {
+    left: i32,
+    right: i32,
+}
+
+/// A simple rectangle type representing a wall of any size
+pub struct TileRect {
+    left: i32,
+    right: i32,
+    top: i32,
+    bottom: i32,
+}
+
+impl TileRect {
+    pub fn half_extents(&self, grid_size: i32) -> Vec2 {
+        Vec2::new(
+            (self.right as f32 - self.left as f32 + 1.) * grid_size as f32 / 2.,
+            (self.top as f32 - self.bottom as f32 + 1.) * grid_size as f32 / 2.,
+        )
+    }
+
+    /// Relative to the level
+    pub fn translation(&self, grid_size: i32) -> Vec3 {
+        Vec3::new(
+            (self.left + self.right + 1) as f32 * grid_size as f32 / 2.,
+            (self.bottom + self.top + 1) as f32 * grid_size as f32 / 2.,
+            0.,
+        )
+    }
+}
+
+/// Stores tiles as GridCoords in a HashSet for quick, easy lookup
+///
+/// The key of this map will be the entity of the level the tile belongs to.
+/// This has two consequences in the resulting collision entities:
+/// 1. it forces the rectangles to be split along level boundaries
+/// 2. it lets us easily add the collision entities as children of the appropriate level entity
+pub fn tiles_by_level<'a, F: QueryFilter>(
+    tiles: impl Iterator<Item = (&'a GridCoords, &'a ChildOf)>,
+    parent_query: &Query<&ChildOf, F>,
+) -> HashMap<Entity, HashSet<GridCoords>> {
+    let mut level_to_tile_locations: HashMap<Entity, HashSet<GridCoords>> = HashMap::new();
+
+    tiles.for_each(|(&grid_coords, child_of)| {
+        // An intgrid tile's direct parent will be a layer entity, not the level entity
+        // To get the level entity, you need the tile's parent_child_of.
+        // This is where parent_query comes in.
+        if let Ok(parent_child_of) = parent_query.get(child_of.parent()) {
+            level_to_tile_locations
+                .entry(parent_child_of.parent())
+                .or_default()
+                .insert(grid_coords);
+        }
+    });
+
+    level_to_tile_locations
+}
+
+/// Merges the tiles of a level into as few rectangles as possible
+///
+/// In basic terms, it will:
+/// 1. combine tiles into flat "plates" in each individual row
+/// 2. combine the plates into rectangles across multiple rows wherever possible
+pub fn merge_tiles(tiles: &HashSet<GridCoords>, width: i32, height: i32) -> Vec<TileRect> {
+    // combine tiles into flat "plates" in each individual row
+    let mut plate_stack: Vec<Vec<Plate>> = Vec::new();
+
+    for y in 0..height {
+        let mut row_plates: Vec<Plate> = Vec::new();
+        let mut plate_start = None;
+
+        // + 1 to the width so the algorithm "terminates" plates that touch the right edge
+        for x in 0..width + 1 {
+            match (plate_start, tiles.contains(&GridCoords { x, y })) {
+                (Some(s), false) => {
+                    row_plates.push(Plate {
+                        left: s,
+                        right: x - 1,
+                    });
+                    plate_start = None;
+                }
+                (None, true) => plate_start = Some(x),
+                _ => (),
+            }
+        }
+
+        plate_stack.push(row_plates);
+    }
+
+    // combine "plates" into rectangles across multiple rows
+    let mut rect_builder: HashMap<Plate, TileRect> = HashMap::new();
+    let mut prev_row: Vec<Plate> = Vec::new();
+    let mut rects: Vec<TileRect> = Vec::new();
+
+    // an extra empty row so the algorithm "finishes" the rects that touch the top edge
+    plate_stack.push(Vec::new());
+
+    for (y, current_row) in plate_stack.into_iter().enumerate() {
+        for prev_plate in &prev_row {
+            if !current_row.contains(prev_plate) {
+                // remove the finished rect so that the same plate in the future starts a new rect
+                if let Some(rect) = rect_builder.remove(prev_plate) {
+                    rects.push(rect);
+                }
+            }
+        }
+        for plate in &current_row {
+            rect_builder
+                .entry(plate.clone())
+                .and_modify(|e| e.top += 1)
+                .or_insert(TileRect {
+                    bottom: y as i32,
+                    top: y as i32,
+                    left: plate.left,
+                    right: plate.right,
+                });
+        }
+        prev_row = current_row;
+    }
+
+    rects
+}
+
+/// Size of the level's grid, in tiles, and of a single tile, in pixels
+pub fn level_grid(
+    level_iid: &LevelIid,
+    ldtk_projects: &Query<&LdtkProjectHandle>,
+    ldtk_project_assets: &Assets<LdtkProject>,
+) -> (i32, i32, i32) {
+    let ldtk_project = ldtk_project_assets
+        .get(ldtk_projects.single().unwrap())
+        .expect("Project should be loaded if level has spawned");
+
+    let level = ldtk_project
+        .as_standalone()
+        .get_loaded_level_by_iid(&level_iid.to_string())
+        .expect("Spawned level should exist in LDtk project");
+
+    let LayerInstance {
+        c_wid: width,
+        c_hei: height,
+        grid_size,
+        ..
+    } = level.layer_instances()[0];
+
+    (width, height, grid_size)
+}
+
 /// Spawns heron collisions for the walls of a level
 ///
 /// You could just insert a ColliderBundle into the WallBundle,
@@ -23,12 +174,7 @@ pub struct WallBundle {
 /// we can minimize the amount of colliding entities.
 ///
 /// The algorithm used here is a nice compromise between simplicity, speed,
-/// and a small number of rectangle colliders.
-/// In basic terms, it will:
-/// 1. consider where the walls are
-/// 2. combine wall tiles into flat "plates" in each individual row
-/// 3. combine the plates into rectangles across multiple rows wherever possible
-/// 4. spawn colliders for each rectangle
+/// and a small number of rectangle colliders, see `merge_tiles`.
 pub fn spawn_wall_collision(
     mut commands: Commands,
     wall_query: Query<(&GridCoords, &ChildOf), Added<Wall>>,
@@ -37,150 +183,40 @@ pub fn spawn_wall_collision(
     ldtk_projects: Query<&LdtkProjectHandle>,
     ldtk_project_assets: Res<Assets<LdtkProject>>,
 ) {
-    /// Represents a wide wall that is 1 tile tall
-    /// Used to spawn wall collisions
-    #[derive(Clone, Eq, PartialEq, Debug, Default, Hash)]
-    struct Plate {
-        left: i32,
-        right: i32,
+    if wall_query.is_empty() {
+        return;
     }
 
-    /// A simple rectangle type representing a wall of any size
-    struct Rect {
-        left: i32,
-        right: i32,
-        top: i32,
-        bottom: i32,
-    }
-
-    // Consider where the walls are
-    // storing them as GridCoords in a HashSet for quick, easy lookup
-    //
-    // The key of this map will be the entity of the level the wall belongs to.
-    // This has two consequences in the resulting collision entities:
-    // 1. it forces the walls to be split along level boundaries
-    // 2. it lets us easily add the collision entities as children of the appropriate level entity
-    let mut level_to_wall_locations: HashMap<Entity, HashSet<GridCoords>> = HashMap::new();
-
-    wall_query.iter().for_each(|(&grid_coords, child_of)| {
-        // An intgrid tile's direct parent will be a layer entity, not the level entity
-        // To get the level entity, you need the tile's parent_child_of.
-        // This is where parent_query comes in.
-        if let Ok(parent_child_of) = parent_query.get(child_of.parent()) {
-            level_to_wall_locations
-                .entry(parent_child_of.parent())
-                .or_default()
-                .insert(grid_coords);
+    let level_to_wall_locations = tiles_by_level(wall_query.iter(), &parent_query);
+
+    level_query.iter().for_each(|(level_entity, level_iid)| {
+        if let Some(level_walls) = level_to_wall_locations.get(&level_entity) {
+            let (width, height, grid_size) =
+                level_grid(level_iid, &ldtk_projects, &ldtk_project_assets);
+
+            let wall_rects = merge_tiles(level_walls, width, height);
+
+            commands.entity(level_entity).with_children(|level| {
+                // Spawn colliders for every rectangle..
+                // Making the collider a child of the level serves two purposes:
+                // 1. Adjusts the transforms to be relative to the level for free
+                // 2. the colliders will be despawned automatically when levels unload
+                for wall_rect in wall_rects {
+                    let half_extents = wall_rect.half_extents(grid_size);
+                    level
+                        .spawn_empty()
+                        .insert(Collider::cuboid(half_extents.x, half_extents.y))
+                        .insert(RigidBody::Fixed)
+                        .insert(Friction::new(0.))
+                        .insert(Restitution::new(0.))
+                        .insert(Transform::from_translation(
+                            wall_rect.translation(grid_size),
+                        ))
+                        .insert(GlobalTransform::default());
+                }
+            });
         }
     });
-
-    if !wall_query.is_empty() {
-        level_query.iter().for_each(|(level_entity, level_iid)| {
-            if let Some(level_walls) = level_to_wall_locations.get(&level_entity) {
-                let ldtk_project = ldtk_project_assets
-                    .get(ldtk_projects.single().unwrap())
-                    .expect("Project should be loaded if level has spawned");
-
-                let level = ldtk_project
-                    .as_standalone()
-                    .get_loaded_level_by_iid(&level_iid.to_string())
-                    .expect("Spawned level should exist in LDtk project");
-
-                let LayerInstance {
-                    c_wid: width,
-                    c_hei: height,
-                    grid_size,
-                    ..
-                } = level.layer_instances()[0];
-
-                // combine wall tiles into flat "plates" in each individual row
-                let mut plate_stack: Vec<Vec<Plate>> = Vec::new();
-
-                for y in 0..height {
-                    let mut row_plates: Vec<Plate> = Vec::new();
-                    let mut plate_start = None;
-
-                    // + 1 to the width so the algorithm "terminates" plates that touch the right edge
-                    for x in 0..width + 1 {
-                        match (plate_start, level_walls.contains(&GridCoords { x, y })) {
-                            (Some(s), false) => {
-                                row_plates.push(Plate {
-                                    left: s,
-                                    right: x - 1,
-                                });
-                                plate_start = None;
-                            }
-                            (None, true) => plate_start = Some(x),
-                            _ => (),
-                        }
-                    }
-
-                    plate_stack.push(row_plates);
-                }
-
-                // combine "plates" into rectangles across multiple rows
-                let mut rect_builder: HashMap<Plate, Rect> = HashMap::new();
-                let mut prev_row: Vec<Plate> = Vec::new();
-                let mut wall_rects: Vec<Rect> = Vec::new();
-
-                // an extra empty row so the algorithm "finishes" the rects that touch the top edge
-                plate_stack.push(Vec::new());
-
-                for (y, current_row) in plate_stack.into_iter().enumerate() {
-                    for prev_plate in &prev_row {
-                        if !current_row.contains(prev_plate) {
-                            // remove the finished rect so that the same plate in the future starts a new rect
-                            if let Some(rect) = rect_builder.remove(prev_plate) {
-                                wall_rects.push(rect);
-                            }
-                        }
-                    }
-                    for plate in &current_row {
-                        rect_builder
-                            .entry(plate.clone())
-                            .and_modify(|e| e.top += 1)
-                            .or_insert(Rect {
-                                bottom: y as i32,
-                                top: y as i32,
-                                left: plate.left,
-                                right: plate.right,
-                            });
-                    }
-                    prev_row = current_row;
-                }
-
-                commands.entity(level_entity).with_children(|level| {
-                    // Spawn colliders for every rectangle..
-                    // Making the collider a child of the level serves two purposes:
-                    // 1. Adjusts the transforms to be relative to the level for free
-                    // 2. the colliders will be despawned automatically when levels unload
-                    for wall_rect in wall_rects {
-                        level
-                            .spawn_empty()
-                            .insert(Collider::cuboid(
-                                (wall_rect.right as f32 - wall_rect.left as f32 + 1.)
-                                    * grid_size as f32
-                                    / 2.,
-                                (wall_rect.top as f32 - wall_rect.bottom as f32 + 1.)
-                                    * grid_size as f32
-                                    / 2.,
-                            ))
-                            .insert(RigidBody::Fixed)
-                            .insert(Friction::new(0.))
-                            .insert(Restitution::new(0.))
-                            .insert(Transform::from_xyz(
-                                (wall_rect.left + wall_rect.right + 1) as f32 * grid_size as f32
-                                    / 2.,
-                                (wall_rect.bottom + wall_rect.top + 1) as f32 * grid_size as f32
-                                    / 2.,
-                                0.,
-                            ))
-                            .insert(GlobalTransform::default());
-                    }
-                });
-            }
-        });
-    }
 }
 
 /// Plugin which spawns walls on appropriate LDtk int cells,
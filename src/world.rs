@@ -65,7 +65,7 @@ pub struct WorldPlugin;
 
 impl Plugin for WorldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, setup)
+        app.add_systems(OnEnter(AppState::LoadingLevel), setup)
             .add_systems(Update, update_level_selection);
     }
 }